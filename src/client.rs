@@ -1,23 +1,29 @@
+use std::sync::Arc;
+
 use reqwest::Client as ReqwestClient;
 
 use crate::constants::{API_BASE_URL, API_VERSION, VERSION};
-use crate::config::Config;
+use crate::config::{Config, ConfigLoader, Credentials};
 use crate::error::Error;
+use crate::rate_limit::{RateLimiter, RateLimiterStats};
+use crate::telemetry;
 
+/// UpCloud API client.
+///
+/// Cloning a client is cheap. Clones share the connection pool and the
+/// rate limiter.
+#[derive(Clone)]
 pub struct Client {
     config: Config,
     client: ReqwestClient,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl Client {
+    /// Creates a client from the environment and the UpCloud config file.
+    /// See [`ConfigLoader`] for the sources and their precedence.
     pub fn new() -> Result<Self, Error> {
-        let config = Config::new(
-            std::env::var("UPCLOUD_USERNAME")
-                .map_err(|_| Error::ConfigError("UPCLOUD_USERNAME environment variable not set".to_string()))?,
-            std::env::var("UPCLOUD_PASSWORD")
-                .map_err(|_| Error::ConfigError("UPCLOUD_PASSWORD environment variable not set".to_string()))?
-        );
-        Self::with_config(config)
+        Self::with_config(ConfigLoader::new().load()?)
     }
 
     pub fn with_config(config: Config) -> Result<Self, Error> {
@@ -34,7 +40,17 @@ impl Client {
 
         let client = client_builder.build()?;
 
-        Ok(Self { config, client })
+        let rate_limiter = match config.rate_limit.as_ref() {
+            Some(rate_limit) => Some(Arc::new(RateLimiter::new(rate_limit)?)),
+            None => None,
+        };
+
+        Ok(Self { config, client, rate_limiter })
+    }
+
+    /// Returns the current state of the rate limiter, if one is configured.
+    pub fn rate_limiter_stats(&self) -> Option<RateLimiterStats> {
+        self.rate_limiter.as_ref().map(|limiter| limiter.stats())
     }
 
     pub(crate) async fn get(&self, path: &str) -> Result<String, Error> {
@@ -46,7 +62,6 @@ impl Client {
         path: &str,
         body: Option<&T>,
     ) -> Result<String, Error> {
-        println!("POST: {:?}", body);
         self.request(reqwest::Method::POST, path, body).await
     }
 
@@ -58,15 +73,64 @@ impl Client {
         self.request(reqwest::Method::PUT, path, body).await
     }
 
+    pub(crate) async fn patch<T: serde::Serialize + std::fmt::Debug>(
+        &self,
+        path: &str,
+        body: Option<&T>,
+    ) -> Result<String, Error> {
+        self.request(reqwest::Method::PATCH, path, body).await
+    }
+
     pub(crate) async fn delete(&self, path: &str) -> Result<String, Error> {
         self.request(reqwest::Method::DELETE, path, Option::<&()>::None).await
     }
 
+    /// Uploads a raw request body to an absolute URL, such as the
+    /// `direct_upload_url` of a storage import. The URL carries its own
+    /// session, so no API credentials are sent.
+    pub(crate) async fn put_raw(
+        &self,
+        url: &str,
+        body: reqwest::Body,
+        content_type: &str,
+    ) -> Result<String, Error> {
+        let response = self.client
+            .put(url)
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(body)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            return Err(Error::from_response(status, response.text().await?));
+        }
+
+        Ok(response.text().await?)
+    }
+
     async fn request<T: serde::Serialize + std::fmt::Debug>(
         &self,
         method: reqwest::Method,
         path: &str,
         body: Option<&T>,
+    ) -> Result<String, Error> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+            let span = telemetry::request_span(&method, path);
+            self.send_request(method, path, body).instrument(span).await
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.send_request(method, path, body).await
+    }
+
+    async fn send_request<T: serde::Serialize + std::fmt::Debug>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&T>,
     ) -> Result<String, Error> {
         let url = format!(
             "{}/{}/{}",
@@ -75,23 +139,281 @@ impl Client {
             path.trim_start_matches('/')
         );
 
-        let mut builder = self.client.request(method, &url);
-        
-        builder = builder.basic_auth(&self.config.username, Some(&self.config.password));
+        let max_attempts = match self.config.retry_policy.as_ref() {
+            Some(policy) if policy.should_retry_method(&method) => policy.max_attempts.max(1),
+            _ => 1,
+        };
+
+        let mut attempt = 1;
+        let mut refreshed_token = None;
+        loop {
+            // Wait for the limiter first so that credentials are resolved,
+            // and a provider's token fetched, just before sending.
+            let permit = match self.rate_limiter.as_ref() {
+                Some(limiter) => Some(limiter.acquire().await),
+                None => None,
+            };
 
-        if let Some(body) = body {
-            builder = builder.json(body);
+            let mut builder = self.client.request(method.clone(), &url);
+
+            builder = match &self.config.credentials {
+                Credentials::Basic { username, password } => builder.basic_auth(username, Some(password)),
+                Credentials::Bearer(token) => builder.bearer_auth(token),
+                Credentials::Provider(provider) => match refreshed_token.as_ref() {
+                    Some(token) => builder.bearer_auth(token),
+                    None => builder.bearer_auth(provider.token().await?),
+                },
+            };
+
+            if let Some(body) = body {
+                telemetry::request_body(body);
+                builder = builder.json(body);
+            }
+
+            let started = std::time::Instant::now();
+            let result = builder.send().await;
+            match &result {
+                Ok(response) => telemetry::response(response, started.elapsed(), attempt),
+                Err(e) => telemetry::request_failed(e, started.elapsed(), attempt),
+            }
+
+            let retry_after = match result {
+                Ok(response) if response.status().is_success() => {
+                    return Ok(response.text().await?);
+                }
+                Ok(response) if response.status() == reqwest::StatusCode::UNAUTHORIZED
+                    && refreshed_token.is_none() =>
+                {
+                    // A rejected token is refreshed and the request retried
+                    // once, without counting against the retry policy.
+                    if let Credentials::Provider(provider) = &self.config.credentials {
+                        refreshed_token = Some(provider.refresh().await?);
+                        continue;
+                    }
+                    return Err(Error::from_response(401, response.text().await?));
+                }
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    let retry_after = retry_after(&response);
+                    let err = Error::from_response(status, response.text().await?);
+                    if attempt >= max_attempts || !is_retryable_status(status) {
+                        return Err(err);
+                    }
+                    retry_after
+                }
+                Err(e) => {
+                    if attempt >= max_attempts || !(e.is_connect() || e.is_timeout()) {
+                        return Err(e.into());
+                    }
+                    None
+                }
+            };
+
+            drop(permit);
+            if let Some(policy) = self.config.retry_policy.as_ref() {
+                let delay = retry_after
+                    .map(|delay| delay.min(policy.max_backoff))
+                    .unwrap_or_else(|| policy.backoff(attempt));
+                tokio::time::sleep(delay).await;
+            }
+            attempt += 1;
         }
+    }
+}
 
-        let response = builder.send().await?;
-        
-        if !response.status().is_success() {
-            return Err(Error::ApiError {
-                status: response.status().as_u16(),
-                message: response.text().await?,
-            });
+fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 429 | 502 | 503 | 504)
+}
+
+// Retry-After is either a number of seconds or an HTTP-date; a date in the
+// past means the request may be retried immediately.
+fn retry_after(response: &reqwest::Response) -> Option<std::time::Duration> {
+    let value = response.headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(std::time::SystemTime::now()).unwrap_or_default())
+}
+
+#[tokio::test]
+async fn test_retry_on_service_unavailable() {
+    use crate::config::RetryPolicy;
+    use crate::resources::server::ServerOperations;
+    use std::time::Duration;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let unavailable = server.mock("GET", "/1.3/server")
+        .with_status(503)
+        .with_header("Retry-After", "0")
+        .expect(2)
+        .create();
+    let ok = server.mock("GET", "/1.3/server")
+        .with_status(200)
+        .with_body(r#"{"servers":{"server":[]}}"#)
+        .expect(1)
+        .create();
+
+    let client = Client::with_config(
+        Config::new("foo", "bar")
+            .with_base_url(url)
+            .with_retry_policy(RetryPolicy::new().with_initial_backoff(Duration::ZERO))
+    ).unwrap();
+
+    let result = client.list_servers().await.unwrap();
+    assert!(result.server.is_empty());
+    unavailable.assert();
+    ok.assert();
+}
+
+#[tokio::test]
+async fn test_retry_after_capped_at_max_backoff() {
+    use crate::config::RetryPolicy;
+    use crate::resources::server::ServerOperations;
+    use std::time::Duration;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let unavailable = server.mock("GET", "/1.3/server")
+        .with_status(503)
+        .with_header("Retry-After", "3600")
+        .expect(1)
+        .create();
+    let ok = server.mock("GET", "/1.3/server")
+        .with_status(200)
+        .with_body(r#"{"servers":{"server":[]}}"#)
+        .expect(1)
+        .create();
+
+    let client = Client::with_config(
+        Config::new("foo", "bar")
+            .with_base_url(url)
+            .with_retry_policy(RetryPolicy::new().with_max_backoff(Duration::from_millis(10)))
+    ).unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(5), client.list_servers()).await
+        .expect("Retry-After should be capped at max_backoff")
+        .unwrap();
+    assert!(result.server.is_empty());
+    unavailable.assert();
+    ok.assert();
+}
+
+#[tokio::test]
+async fn test_no_retry_for_non_idempotent_method() {
+    use crate::config::RetryPolicy;
+    use crate::resources::server::ServerOperations;
+    use crate::types::server::CreateServerRequest;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let rate_limited = server.mock("POST", "/1.3/server")
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .expect(1)
+        .create();
+
+    let client = Client::with_config(
+        Config::new("foo", "bar")
+            .with_base_url(url)
+            .with_retry_policy(RetryPolicy::new())
+    ).unwrap();
+
+    let err = client.create_server(&CreateServerRequest::new().build()).await.unwrap_err();
+    assert!(err.is_rate_limited());
+    rate_limited.assert();
+}
+
+#[tokio::test]
+async fn test_token_provider_refresh_on_unauthorized() {
+    use crate::config::TokenProvider;
+    use crate::resources::server::ServerOperations;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct RotatingToken {
+        rotated: AtomicBool,
+    }
+
+    #[async_trait::async_trait]
+    impl TokenProvider for RotatingToken {
+        async fn token(&self) -> Result<String, Error> {
+            Ok(if self.rotated.load(Ordering::SeqCst) { "fresh" } else { "stale" }.to_string())
         }
 
-        Ok(response.text().await?)
+        async fn refresh(&self) -> Result<String, Error> {
+            self.rotated.store(true, Ordering::SeqCst);
+            self.token().await
+        }
     }
-}
\ No newline at end of file
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let stale = server.mock("GET", "/1.3/server")
+        .match_header("authorization", "Bearer stale")
+        .with_status(401)
+        .with_body(r#"{"error":{"error_code":"AUTHENTICATION_FAILED","error_message":"Authentication failed using the given username and password."}}"#)
+        .expect(1)
+        .create();
+    let fresh = server.mock("GET", "/1.3/server")
+        .match_header("authorization", "Bearer fresh")
+        .with_status(200)
+        .with_body(r#"{"servers":{"server":[]}}"#)
+        .expect(1)
+        .create();
+
+    let client = Client::with_config(
+        Config::from_credentials(Credentials::provider(RotatingToken { rotated: AtomicBool::new(false) }))
+            .with_base_url(url)
+    ).unwrap();
+
+    client.list_servers().await.unwrap();
+    stale.assert();
+    fresh.assert();
+}
+
+#[tokio::test]
+async fn test_max_in_flight_shared_across_clones() {
+    use crate::config::RateLimit;
+    use crate::resources::server::ServerOperations;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/server")
+        .with_status(200)
+        .with_body(r#"{"servers":{"server":[]}}"#)
+        .expect(2)
+        .create();
+
+    let client = Client::with_config(
+        Config::new("foo", "bar")
+            .with_base_url(url)
+            .with_rate_limit(RateLimit::new().with_max_in_flight(1))
+    ).unwrap();
+    let clone = client.clone();
+
+    // Hold the only slot, so a request through the clone has to queue.
+    let limiter = client.rate_limiter.as_ref().unwrap();
+    let permit = limiter.acquire().await;
+    assert_eq!(clone.rate_limiter_stats().unwrap().in_flight, 1);
+
+    let request = tokio::spawn(async move { clone.list_servers().await });
+    while client.rate_limiter_stats().unwrap().queued == 0 {
+        tokio::task::yield_now().await;
+    }
+
+    drop(permit);
+    request.await.unwrap().unwrap();
+    client.list_servers().await.unwrap();
+
+    let stats = client.rate_limiter_stats().unwrap();
+    assert_eq!(stats.queued, 0);
+    assert_eq!(stats.in_flight, 0);
+    assert_eq!(stats.total_requests, 3);
+}
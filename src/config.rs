@@ -1,25 +1,133 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use reqwest::ClientBuilder;
 use std::sync::Arc;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::error::Error;
+
+/// Supplies bearer tokens on demand, e.g. from a secrets manager.
+///
+/// [`TokenProvider::token`] is called before every request, so it should
+/// cache the token. If the API rejects a token with `401 Unauthorized`,
+/// [`TokenProvider::refresh`] is called and the request is retried once.
+#[async_trait]
+pub trait TokenProvider: Send + Sync {
+    async fn token(&self) -> Result<String, Error>;
+
+    async fn refresh(&self) -> Result<String, Error> {
+        self.token().await
+    }
+}
+
+/// Credentials used to authenticate API requests.
+#[derive(Clone)]
+pub enum Credentials {
+    Basic {
+        username: String,
+        password: String,
+    },
+    Bearer(String),
+    Provider(Arc<dyn TokenProvider>),
+}
+
+impl Credentials {
+    pub fn basic<S: Into<String>>(username: S, password: S) -> Self {
+        Self::Basic {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Self::Bearer(token.into())
+    }
+
+    pub fn provider(provider: impl TokenProvider + 'static) -> Self {
+        Self::Provider(Arc::new(provider))
+    }
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &"<redacted>")
+                .finish(),
+            Self::Bearer(_) => f.debug_tuple("Bearer").field(&"<redacted>").finish(),
+            Self::Provider(_) => f.debug_tuple("Provider").finish(),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct Config {
-    pub username: String,
-    pub password: String,
+    pub credentials: Credentials,
     pub base_url: Option<String>,
     pub timeout: Option<Duration>,
     pub http_client_hook: Option<Arc<dyn Fn(ClientBuilder) -> ClientBuilder + Send + Sync>>,
+    pub retry_policy: Option<RetryPolicy>,
+    pub rate_limit: Option<RateLimit>,
+}
 
+impl std::fmt::Debug for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Config")
+            .field("credentials", &self.credentials)
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("http_client_hook", &self.http_client_hook.as_ref().map(|_| "<fn>"))
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limit", &self.rate_limit)
+            .finish()
+    }
 }
 
 impl Config {
     pub fn new<S: Into<String>>(username: S, password: S) -> Self {
+        Self::from_credentials(Credentials::basic(username, password))
+    }
+
+    /// Creates a config that authenticates with a static API token.
+    pub fn from_token(token: impl Into<String>) -> Self {
+        Self::from_credentials(Credentials::bearer(token))
+    }
+
+    pub fn from_credentials(credentials: Credentials) -> Self {
         Self {
-            username: username.into(),
-            password: password.into(),
+            credentials,
             base_url: None,
             timeout: None,
             http_client_hook: None,
+            retry_policy: None,
+            rate_limit: None,
+        }
+    }
+
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// The username of basic auth credentials.
+    #[deprecated(note = "match on `Config::credentials` instead")]
+    pub fn username(&self) -> Option<&str> {
+        match &self.credentials {
+            Credentials::Basic { username, .. } => Some(username),
+            _ => None,
+        }
+    }
+
+    /// The password of basic auth credentials.
+    #[deprecated(note = "match on `Config::credentials` instead")]
+    pub fn password(&self) -> Option<&str> {
+        match &self.credentials {
+            Credentials::Basic { password, .. } => Some(password),
+            _ => None,
         }
     }
 
@@ -37,4 +145,489 @@ impl Config {
         self.http_client_hook = Some(hook);
         self
     }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+}
+
+/// Retry policy for transient failures.
+///
+/// Requests failing with a connection error, a timeout or a `429`, `502`,
+/// `503` or `504` response are retried with exponential backoff. A
+/// `Retry-After` header, in either its delay-seconds or HTTP-date form,
+/// takes precedence over the computed backoff but is capped at
+/// `max_backoff`. Only idempotent methods (GET, PUT, DELETE) are retried
+/// unless [`RetryPolicy::with_retry_non_idempotent`] is set.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub jitter: bool,
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn with_retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    pub(crate) fn should_retry_method(&self, method: &reqwest::Method) -> bool {
+        self.retry_non_idempotent || method.is_idempotent()
+    }
+
+    /// Backoff before the given retry, where `retry` starts from 1.
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(31);
+        let backoff = self.initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+
+        if !self.jitter {
+            return backoff;
+        }
+
+        // Equal jitter: half of the backoff is fixed, the other half random.
+        let half = backoff / 2;
+        let max_jitter = half.as_nanos().min(u64::MAX as u128) as u64;
+        half + Duration::from_nanos(fastrand::u64(..=max_jitter))
+    }
+}
+
+/// Client-side limits on request rate and concurrency.
+///
+/// The rate is enforced with a token bucket that holds up to `burst`
+/// requests and refills at `requests_per_second`. Each HTTP attempt,
+/// including retries, takes one token and then waits for a free in-flight
+/// slot, so slots are only held by requests that are ready to be sent. The
+/// limits are shared by all clones of a [`Client`](crate::client::Client).
+#[derive(Debug, Clone, Default)]
+pub struct RateLimit {
+    pub requests_per_second: Option<f64>,
+    pub burst: u32,
+    pub max_in_flight: Option<usize>,
+}
+
+impl RateLimit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the sustained request rate. The burst defaults to one
+    /// second's worth of requests.
+    pub fn with_requests_per_second(mut self, requests_per_second: f64) -> Self {
+        self.requests_per_second = Some(requests_per_second);
+        if self.burst == 0 {
+            self.burst = requests_per_second.ceil().max(1.0) as u32;
+        }
+        self
+    }
+
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Limits the number of requests waiting for a response at once.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight);
+        self
+    }
+}
+
+/// Loads a [`Config`] from a profile file, environment variables and
+/// explicit values.
+///
+/// Each setting is taken from the first source that sets it, in this order:
+///
+/// 1. values set on the loader, e.g. [`ConfigLoader::with_base_url`]
+/// 2. environment variables: `UPCLOUD_TOKEN`, `UPCLOUD_USERNAME` and
+///    `UPCLOUD_PASSWORD`, `UPCLOUD_API_URL`, `UPCLOUD_TIMEOUT` (seconds)
+/// 3. the selected profile of the config file
+///
+/// Within a source, a token takes precedence over a username and password.
+///
+/// The config file is read from [`ConfigLoader::with_file`], `UPCLOUD_CONFIG`
+/// or `$XDG_CONFIG_HOME/upcloud/config.toml` (falling back to
+/// `~/.config/upcloud/config.toml`), in that order. Only the default
+/// location may be missing, and it is not read at all when the loader and
+/// environment already set the credentials, base URL and timeout. The
+/// profile is chosen by [`ConfigLoader::with_profile`], `UPCLOUD_PROFILE`,
+/// the file's `default_profile` or a profile named `default`, in that order:
+///
+/// ```toml
+/// default_profile = "production"
+///
+/// [profiles.production]
+/// token = "ucat_..."
+///
+/// [profiles.staging]
+/// username = "staging-api"
+/// password = "..."
+/// api_url = "https://api.staging.example.com"
+/// timeout = 60
+/// ```
+#[derive(Default)]
+pub struct ConfigLoader {
+    profile: Option<String>,
+    file: Option<PathBuf>,
+    credentials: Option<Credentials>,
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    // Replaces the process environment, for tests.
+    env: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    default_profile: Option<String>,
+    #[serde(default)]
+    profiles: HashMap<String, Profile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Profile {
+    username: Option<String>,
+    password: Option<String>,
+    token: Option<String>,
+    api_url: Option<String>,
+    timeout: Option<u64>,
+}
+
+impl ConfigLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    pub fn with_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.file = Some(path.into());
+        self
+    }
+
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn load(self) -> Result<Config, Error> {
+        let credentials = match self.credentials.clone() {
+            Some(credentials) => Some(credentials),
+            None => self.env_credentials()?,
+        };
+
+        let base_url = self.base_url.clone().or_else(|| self.env_var("UPCLOUD_API_URL"));
+
+        let timeout = match self.timeout {
+            Some(timeout) => Some(timeout),
+            None => self.env_var("UPCLOUD_TIMEOUT")
+                .map(|value| parse_timeout(&value).map_err(|e| {
+                    Error::ConfigError(format!("environment variable UPCLOUD_TIMEOUT: {}", e))
+                }))
+                .transpose()?,
+        };
+
+        // A file at the default location is only read if it can still supply
+        // something, so a broken one there does not get in the way of fully
+        // explicit or environment-based configuration.
+        let explicit_file = self.file.is_some() || self.env_var("UPCLOUD_CONFIG").is_some();
+        let profile = if explicit_file || credentials.is_none() || base_url.is_none() || timeout.is_none() {
+            self.load_profile()?
+        } else {
+            None
+        };
+
+        let credentials = match credentials {
+            Some(credentials) => credentials,
+            None => profile_credentials(&profile)?.ok_or_else(|| {
+                Error::ConfigError(
+                    "no credentials found: set UPCLOUD_TOKEN, UPCLOUD_USERNAME and UPCLOUD_PASSWORD, or a profile in the config file".to_string(),
+                )
+            })?,
+        };
+        let base_url = base_url.or_else(|| profile.as_ref().and_then(|(_, p)| p.api_url.clone()));
+        let timeout = timeout.or_else(|| profile.as_ref().and_then(|(_, p)| p.timeout).map(Duration::from_secs));
+
+        let mut config = Config::from_credentials(credentials);
+        config.base_url = base_url;
+        config.timeout = timeout;
+        Ok(config)
+    }
+
+    fn env_var(&self, key: &str) -> Option<String> {
+        match &self.env {
+            Some(env) => env.get(key).cloned(),
+            None => std::env::var(key).ok(),
+        }
+        .filter(|v| !v.is_empty())
+    }
+
+    fn env_credentials(&self) -> Result<Option<Credentials>, Error> {
+        if let Some(token) = self.env_var("UPCLOUD_TOKEN") {
+            return Ok(Some(Credentials::bearer(token)));
+        }
+        match (self.env_var("UPCLOUD_USERNAME"), self.env_var("UPCLOUD_PASSWORD")) {
+            (Some(username), Some(password)) => Ok(Some(Credentials::basic(username, password))),
+            (Some(_), None) => Err(Error::ConfigError(
+                "environment variable UPCLOUD_PASSWORD: not set, but UPCLOUD_USERNAME is".to_string(),
+            )),
+            (None, Some(_)) => Err(Error::ConfigError(
+                "environment variable UPCLOUD_USERNAME: not set, but UPCLOUD_PASSWORD is".to_string(),
+            )),
+            (None, None) => Ok(None),
+        }
+    }
+
+    // Returns the selected profile along with a description of where it came
+    // from, for error messages.
+    fn load_profile(&self) -> Result<Option<(String, Profile)>, Error> {
+        let (path, required) = match (&self.file, self.env_var("UPCLOUD_CONFIG")) {
+            (Some(path), _) => (path.clone(), true),
+            (None, Some(path)) => (PathBuf::from(path), true),
+            (None, None) => match self.default_file() {
+                Some(path) => (path, false),
+                None => return Ok(None),
+            },
+        };
+
+        let file = match read_config_file(&path) {
+            Ok(file) => file,
+            Err(e) if !required && e.kind() == std::io::ErrorKind::NotFound => ConfigFile::default(),
+            Err(e) => {
+                return Err(Error::ConfigError(format!("config file {}: {}", path.display(), e)));
+            }
+        };
+
+        let selected = self.profile.clone().or_else(|| self.env_var("UPCLOUD_PROFILE"));
+        let name = match selected {
+            Some(name) => name,
+            None => match file.default_profile {
+                Some(name) => name,
+                None if file.profiles.contains_key("default") => "default".to_string(),
+                None => return Ok(None),
+            },
+        };
+
+        let mut profiles = file.profiles;
+        match profiles.remove(&name) {
+            Some(profile) => Ok(Some((format!("profile '{}' in {}", name, path.display()), profile))),
+            None => Err(Error::ConfigError(format!(
+                "config file {}: profile '{}' not found",
+                path.display(),
+                name
+            ))),
+        }
+    }
+
+    fn default_file(&self) -> Option<PathBuf> {
+        let config_home = self.env_var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| self.env_var("HOME").map(|home| Path::new(&home).join(".config")))?;
+        Some(config_home.join("upcloud").join("config.toml"))
+    }
+}
+
+fn read_config_file(path: &Path) -> std::io::Result<ConfigFile> {
+    let content = std::fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+fn profile_credentials(profile: &Option<(String, Profile)>) -> Result<Option<Credentials>, Error> {
+    let Some((source, profile)) = profile else {
+        return Ok(None);
+    };
+    if let Some(token) = &profile.token {
+        return Ok(Some(Credentials::bearer(token.clone())));
+    }
+    match (&profile.username, &profile.password) {
+        (Some(username), Some(password)) => Ok(Some(Credentials::basic(username.clone(), password.clone()))),
+        (None, None) => Ok(None),
+        _ => Err(Error::ConfigError(format!(
+            "{}: username and password must be set together",
+            source
+        ))),
+    }
+}
+
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value.trim()
+        .parse::<u64>()
+        .map(Duration::from_secs)
+        .map_err(|_| format!("invalid timeout '{}', expected whole seconds", value))
+}
+
+// A config file at `<dir>/upcloud/config.toml` in a temporary directory that
+// is removed when dropped, so `dir` can also stand in for XDG_CONFIG_HOME.
+#[cfg(test)]
+struct TestConfig {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+#[cfg(test)]
+impl TestConfig {
+    fn new(name: &str, content: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("upcloud-sdk-{}-{}", name, std::process::id()));
+        let path = dir.join("upcloud").join("config.toml");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, content).unwrap();
+        Self { dir, path }
+    }
+}
+
+#[cfg(test)]
+impl Drop for TestConfig {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[test]
+fn test_config_loader_precedence() {
+    let config_file = TestConfig::new("precedence", r#"
+default_profile = "production"
+
+[profiles.production]
+token = "ucat_production"
+api_url = "https://api.production.example.com"
+timeout = 30
+
+[profiles.staging]
+username = "staging-api"
+password = "secret"
+timeout = 60
+"#);
+
+    let loader = ConfigLoader {
+        env: Some(HashMap::from([
+            ("UPCLOUD_PROFILE".to_string(), "staging".to_string()),
+            ("UPCLOUD_TIMEOUT".to_string(), "10".to_string()),
+        ])),
+        ..ConfigLoader::new().with_file(&config_file.path)
+    };
+    let config = loader.with_base_url("http://localhost:8080").load().unwrap();
+    assert!(matches!(config.credentials, Credentials::Basic { ref username, .. } if username == "staging-api"));
+    assert_eq!(config.timeout, Some(Duration::from_secs(10)));
+    assert_eq!(config.base_url.as_deref(), Some("http://localhost:8080"));
+
+    let loader = ConfigLoader {
+        env: Some(HashMap::new()),
+        ..ConfigLoader::new().with_file(&config_file.path)
+    };
+    let config = loader.load().unwrap();
+    assert!(matches!(config.credentials, Credentials::Bearer(ref token) if token == "ucat_production"));
+    assert_eq!(config.base_url.as_deref(), Some("https://api.production.example.com"));
+}
+
+#[test]
+fn test_config_loader_errors_name_source() {
+    let config_file = TestConfig::new("errors", "[profiles.default]\nusername = \"api\"\n");
+    let path = &config_file.path;
+
+    let loader = ConfigLoader {
+        env: Some(HashMap::from([("UPCLOUD_TIMEOUT".to_string(), "soon".to_string())])),
+        ..ConfigLoader::new().with_credentials(Credentials::bearer("token"))
+    };
+    let err = loader.load().unwrap_err().to_string();
+    assert!(err.contains("UPCLOUD_TIMEOUT"), "{}", err);
+
+    let loader = ConfigLoader {
+        env: Some(HashMap::new()),
+        ..ConfigLoader::new().with_file(path)
+    };
+    let err = loader.load().unwrap_err().to_string();
+    assert!(err.contains("profile 'default'"), "{}", err);
+
+    let loader = ConfigLoader {
+        env: Some(HashMap::new()),
+        ..ConfigLoader::new().with_file(path).with_profile("missing")
+    };
+    let err = loader.load().unwrap_err().to_string();
+    assert!(err.contains(&path.display().to_string()) && err.contains("'missing'"), "{}", err);
+}
+
+#[test]
+fn test_config_loader_skips_unneeded_default_file() {
+    let config_file = TestConfig::new("unneeded", "not toml");
+    let env = HashMap::from([
+        ("XDG_CONFIG_HOME".to_string(), config_file.dir.display().to_string()),
+        ("UPCLOUD_TOKEN".to_string(), "ucat_env".to_string()),
+        ("UPCLOUD_API_URL".to_string(), "http://localhost:8080".to_string()),
+    ]);
+
+    let loader = ConfigLoader {
+        env: Some(env.clone()),
+        ..ConfigLoader::new().with_timeout(Duration::from_secs(5))
+    };
+    let config = loader.load().unwrap();
+    assert!(matches!(config.credentials, Credentials::Bearer(ref token) if token == "ucat_env"));
+
+    // Without a timeout the file could still supply one, so it is read.
+    let loader = ConfigLoader {
+        env: Some(env),
+        ..ConfigLoader::new()
+    };
+    let err = loader.load().unwrap_err().to_string();
+    assert!(err.contains(&config_file.path.display().to_string()), "{}", err);
 }
@@ -1,27 +1,232 @@
 use std::fmt;
 
+use serde::Deserialize;
+
 #[derive(Debug)]
 pub enum Error {
     RequestError(reqwest::Error),
+    /// An unsuccessful response from the API.
+    ///
+    /// Match with `..` and prefer [`Error::error_code`] and [`Error::status`]
+    /// so that further fields can be added without breaking callers.
+    #[non_exhaustive]
     ApiError {
         status: u16,
+        code: Option<ErrorCode>,
         message: String,
     },
     SerdeError(serde_json::Error),
+    /// A kubeconfig could not be parsed; see the `kubeconfig` feature.
+    YamlError(Box<dyn std::error::Error + Send + Sync>),
     Timeout,
     ConfigError(String),
+    ValidationError(String),
+    QuotaExceeded {
+        resource: String,
+        requested: i64,
+        available: i64,
+    },
+}
+
+/// Error codes returned by the UpCloud API.
+///
+/// Codes not listed here are kept verbatim as [`ErrorCode::Unknown`]. Later
+/// releases may give such codes their own variant, so compare against
+/// [`ErrorCode::as_str`] if you need to handle a code that is not listed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    AuthenticationFailed,
+    InsufficientCredits,
+    ServerNotFound,
+    ServerForbidden,
+    ServerStateIllegal,
+    StorageNotFound,
+    StorageForbidden,
+    StorageStateIllegal,
+    StorageAttached,
+    StorageInUse,
+    IpAddressNotFound,
+    IpAddressForbidden,
+    ZoneNotFound,
+    PlanNotFound,
+    TagNotFound,
+    TagExists,
+    FirewallRuleNotFound,
+    FirewallRuleExists,
+    NetworkNotFound,
+    RouterNotFound,
+    Unknown(String),
+}
+
+impl ErrorCode {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "AUTHENTICATION_FAILED" => Self::AuthenticationFailed,
+            "INSUFFICIENT_CREDITS" => Self::InsufficientCredits,
+            "SERVER_NOT_FOUND" => Self::ServerNotFound,
+            "SERVER_FORBIDDEN" => Self::ServerForbidden,
+            "SERVER_STATE_ILLEGAL" => Self::ServerStateIllegal,
+            "STORAGE_NOT_FOUND" => Self::StorageNotFound,
+            "STORAGE_FORBIDDEN" => Self::StorageForbidden,
+            "STORAGE_STATE_ILLEGAL" => Self::StorageStateIllegal,
+            "STORAGE_ATTACHED" => Self::StorageAttached,
+            "STORAGE_IN_USE" => Self::StorageInUse,
+            "IP_ADDRESS_NOT_FOUND" => Self::IpAddressNotFound,
+            "IP_ADDRESS_FORBIDDEN" => Self::IpAddressForbidden,
+            "ZONE_NOT_FOUND" => Self::ZoneNotFound,
+            "PLAN_NOT_FOUND" => Self::PlanNotFound,
+            "TAG_NOT_FOUND" => Self::TagNotFound,
+            "TAG_EXISTS" => Self::TagExists,
+            "FIREWALL_RULE_NOT_FOUND" => Self::FirewallRuleNotFound,
+            "FIREWALL_RULE_EXISTS" => Self::FirewallRuleExists,
+            "NETWORK_NOT_FOUND" => Self::NetworkNotFound,
+            "ROUTER_NOT_FOUND" => Self::RouterNotFound,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::AuthenticationFailed => "AUTHENTICATION_FAILED",
+            Self::InsufficientCredits => "INSUFFICIENT_CREDITS",
+            Self::ServerNotFound => "SERVER_NOT_FOUND",
+            Self::ServerForbidden => "SERVER_FORBIDDEN",
+            Self::ServerStateIllegal => "SERVER_STATE_ILLEGAL",
+            Self::StorageNotFound => "STORAGE_NOT_FOUND",
+            Self::StorageForbidden => "STORAGE_FORBIDDEN",
+            Self::StorageStateIllegal => "STORAGE_STATE_ILLEGAL",
+            Self::StorageAttached => "STORAGE_ATTACHED",
+            Self::StorageInUse => "STORAGE_IN_USE",
+            Self::IpAddressNotFound => "IP_ADDRESS_NOT_FOUND",
+            Self::IpAddressForbidden => "IP_ADDRESS_FORBIDDEN",
+            Self::ZoneNotFound => "ZONE_NOT_FOUND",
+            Self::PlanNotFound => "PLAN_NOT_FOUND",
+            Self::TagNotFound => "TAG_NOT_FOUND",
+            Self::TagExists => "TAG_EXISTS",
+            Self::FirewallRuleNotFound => "FIREWALL_RULE_NOT_FOUND",
+            Self::FirewallRuleExists => "FIREWALL_RULE_EXISTS",
+            Self::NetworkNotFound => "NETWORK_NOT_FOUND",
+            Self::RouterNotFound => "ROUTER_NOT_FOUND",
+            Self::Unknown(code) => code,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// {"error":{"error_code":"SERVER_NOT_FOUND","error_message":"..."}}
+#[derive(Deserialize)]
+struct LegacyErrorBody {
+    error: LegacyError,
+}
+
+#[derive(Deserialize)]
+struct LegacyError {
+    error_code: String,
+    error_message: String,
+}
+
+// RFC 7807 problem details used by the newer endpoints, e.g.
+// {"type":"https://developers.upcloud.com/1.3/errors#ERROR_NOT_FOUND","title":"...","status":404}
+#[derive(Deserialize)]
+struct ProblemDetails {
+    #[serde(rename = "type")]
+    problem_type: String,
+    title: String,
+    #[serde(default)]
+    invalid_params: Vec<InvalidParam>,
+}
+
+#[derive(Deserialize)]
+struct InvalidParam {
+    name: String,
+    reason: String,
+}
+
+impl Error {
+    /// Builds an [`Error::ApiError`] from an unsuccessful response, parsing
+    /// the error code and message from the body when possible.
+    pub(crate) fn from_response(status: u16, body: String) -> Self {
+        if let Ok(parsed) = serde_json::from_str::<LegacyErrorBody>(&body) {
+            return Error::ApiError {
+                status,
+                code: Some(ErrorCode::from_code(&parsed.error.error_code)),
+                message: parsed.error.error_message,
+            };
+        }
+
+        if let Ok(problem) = serde_json::from_str::<ProblemDetails>(&body) {
+            let code = problem.problem_type
+                .rsplit_once('#')
+                .map(|(_, code)| ErrorCode::from_code(code));
+            let mut message = problem.title;
+            if !problem.invalid_params.is_empty() {
+                let params = problem.invalid_params
+                    .iter()
+                    .map(|p| format!("{}: {}", p.name, p.reason))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                message = format!("{} ({})", message, params);
+            }
+            return Error::ApiError { status, code, message };
+        }
+
+        Error::ApiError { status, code: None, message: body }
+    }
+
+    /// Returns the API error code, if the error was returned by the API.
+    pub fn error_code(&self) -> Option<&ErrorCode> {
+        match self {
+            Error::ApiError { code, .. } => code.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::ApiError { status, .. } => Some(*status),
+            Error::RequestError(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+            || self.error_code().is_some_and(|code| code.as_str().ends_with("NOT_FOUND"))
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        self.status() == Some(409)
+    }
+
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(429)
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::RequestError(e) => write!(f, "Request error: {}", e),
-            Error::ApiError { status, message } => {
+            Error::ApiError { status, code: Some(code), message } => {
+                write!(f, "API error ({} {}): {}", status, code, message)
+            }
+            Error::ApiError { status, code: None, message } => {
                 write!(f, "API error ({}): {}", status, message)
             }
             Error::SerdeError(e) => write!(f, "Serialization error: {}", e),
+            Error::YamlError(e) => write!(f, "YAML error: {}", e),
             Error::Timeout => write!(f, "Request timed out"),
             Error::ConfigError(e) => write!(f, "Config error: {}", e),
+            Error::ValidationError(e) => write!(f, "Validation error: {}", e),
+            Error::QuotaExceeded { resource, requested, available } => {
+                write!(f, "Quota exceeded for {}: requested {}, available {}", resource, requested, available)
+            }
         }
     }
 }
@@ -38,4 +243,22 @@ impl From<serde_json::Error> for Error {
     fn from(err: serde_json::Error) -> Self {
         Error::SerdeError(err)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "kubeconfig")]
+impl From<serde_norway::Error> for Error {
+    fn from(err: serde_norway::Error) -> Self {
+        Error::YamlError(Box::new(err))
+    }
+}
+
+#[test]
+fn test_from_response_problem_details() {
+    let err = Error::from_response(
+        400,
+        r#"{"type":"https://developers.upcloud.com/1.3/errors#ERROR_INVALID_REQUEST","title":"Validation error.","status":400,"invalid_params":[{"name":"plan","reason":"Plan not found."}]}"#.to_string(),
+    );
+    assert_eq!(err.error_code(), Some(&ErrorCode::Unknown("ERROR_INVALID_REQUEST".to_string())));
+    assert_eq!(err.to_string(), "API error (400 ERROR_INVALID_REQUEST): Validation error. (plan: Plan not found.)");
+    assert!(!err.is_not_found());
+}
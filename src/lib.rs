@@ -18,6 +18,9 @@ pub mod error;
 pub mod config;
 pub mod types;
 pub mod resources;
+pub mod pagination;
+pub mod rate_limit;
 
 mod constants;
+mod telemetry;
 
@@ -0,0 +1,56 @@
+//! Lazily paginated listing.
+//!
+//! The `stream_*` methods of the resource traits fetch one page at a time
+//! as the stream is polled:
+//!
+//! ```rust,no_run
+//! use futures::{StreamExt, TryStreamExt};
+//! use upcloud_sdk::{client::Client, resources::server::ServerOperations};
+//!
+//! # async fn example() -> Result<(), upcloud_sdk::error::Error> {
+//! let client = Client::new()?;
+//! let started: Vec<_> = client.stream_servers(100)
+//!     .try_filter(|s| futures::future::ready(s.state == "started"))
+//!     .take(10)
+//!     .try_collect()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use futures::stream::{self, Stream, TryStreamExt};
+
+use crate::error::Error;
+use crate::types::common::Page;
+
+/// Turns a page fetching function into a stream of items. Fetching stops
+/// at the first empty page.
+///
+/// Each page starts where the items received so far end, so a page size
+/// above what the API returns per request still yields every item.
+pub fn paginate<T, F, Fut>(page_size: u32, mut fetch: F) -> impl Stream<Item = Result<T, Error>>
+where
+    F: FnMut(Page) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, Error>>,
+{
+    let page_size = page_size.max(1);
+    stream::try_unfold(Some(Page::first(page_size)), move |page| {
+        let request = page.map(|page| (page, fetch(page)));
+        async move {
+            let Some((page, request)) = request else {
+                return Ok::<_, Error>(None);
+            };
+            let items = request.await?;
+            let next = if items.is_empty() {
+                None
+            } else {
+                let received = u32::try_from(items.len()).unwrap_or(u32::MAX);
+                Some(Page::new(page.limit, page.offset.saturating_add(received)))
+            };
+            Ok(Some((stream::iter(items.into_iter().map(Ok::<T, Error>)), next)))
+        }
+    })
+    .try_flatten()
+}
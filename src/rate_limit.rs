@@ -0,0 +1,235 @@
+//! Client-side rate limiting, configured with
+//! [`RateLimit`](crate::config::RateLimit).
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use crate::config::RateLimit;
+use crate::error::Error;
+
+/// Enforces a [`RateLimit`] across all clones of a client.
+#[derive(Debug)]
+pub struct RateLimiter {
+    bucket: Option<Mutex<TokenBucket>>,
+    semaphore: Option<Semaphore>,
+    max_in_flight: Option<usize>,
+    queued: AtomicUsize,
+    total_requests: AtomicU64,
+    total_wait_nanos: AtomicU64,
+}
+
+/// A snapshot of the state of a [`RateLimiter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimiterStats {
+    /// Requests waiting for a token or a free in-flight slot.
+    pub queued: usize,
+    pub in_flight: usize,
+    pub max_in_flight: Option<usize>,
+    /// Tokens currently in the bucket. Negative when requests have
+    /// reserved tokens that have not been refilled yet.
+    pub available_tokens: Option<f64>,
+    pub total_requests: u64,
+    /// Total time requests have spent waiting in the limiter.
+    pub total_wait: Duration,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.refilled_at = now;
+    }
+
+    // Takes a token, going into debt if the bucket is empty, and returns how
+    // long the caller must wait for its token to be refilled. Later callers
+    // queue behind earlier ones.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        self.refill(now);
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+/// Holds an in-flight slot until dropped.
+pub(crate) struct RateLimitPermit<'a> {
+    _permit: Option<SemaphorePermit<'a>>,
+}
+
+// Counts a request as queued for as long as it is alive, including when the
+// waiting future is dropped.
+struct QueuedGuard<'a>(&'a AtomicUsize);
+
+impl<'a> QueuedGuard<'a> {
+    fn new(queued: &'a AtomicUsize) -> Self {
+        queued.fetch_add(1, Ordering::SeqCst);
+        Self(queued)
+    }
+}
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// Gives a reserved token back to the bucket unless disarmed, so that an
+// acquire dropped while sleeping does not delay the requests behind it.
+struct ReservationGuard<'a>(Option<&'a Mutex<TokenBucket>>);
+
+impl ReservationGuard<'_> {
+    fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for ReservationGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(bucket) = self.0 {
+            let mut bucket = bucket.lock().unwrap();
+            bucket.tokens = (bucket.tokens + 1.0).min(bucket.capacity);
+        }
+    }
+}
+
+impl RateLimiter {
+    pub(crate) fn new(rate_limit: &RateLimit) -> Result<Self, Error> {
+        let bucket = match rate_limit.requests_per_second {
+            Some(rate) if !(rate > 0.0 && rate.is_finite()) => {
+                return Err(Error::ConfigError(format!("rate limit must be a positive number, got {}", rate)));
+            }
+            Some(rate) => {
+                let capacity = rate_limit.burst.max(1) as f64;
+                Some(Mutex::new(TokenBucket {
+                    rate,
+                    capacity,
+                    tokens: capacity,
+                    refilled_at: Instant::now(),
+                }))
+            }
+            None => None,
+        };
+
+        let semaphore = match rate_limit.max_in_flight {
+            Some(0) => return Err(Error::ConfigError("max_in_flight must be at least 1".to_string())),
+            Some(max) => Some(Semaphore::new(max)),
+            None => None,
+        };
+
+        Ok(Self {
+            bucket,
+            semaphore,
+            max_in_flight: rate_limit.max_in_flight,
+            queued: AtomicUsize::new(0),
+            total_requests: AtomicU64::new(0),
+            total_wait_nanos: AtomicU64::new(0),
+        })
+    }
+
+    /// Waits for a token and then for a free in-flight slot, so that a
+    /// request does not hold a slot while it sleeps for a token.
+    pub(crate) async fn acquire(&self) -> RateLimitPermit<'_> {
+        let started = Instant::now();
+        let queued = QueuedGuard::new(&self.queued);
+
+        if let Some(bucket) = &self.bucket {
+            let wait = bucket.lock().unwrap().reserve(Instant::now());
+            if !wait.is_zero() {
+                let reservation = ReservationGuard(Some(bucket));
+                tokio::time::sleep(wait).await;
+                reservation.disarm();
+            }
+        }
+
+        let permit = match &self.semaphore {
+            // The semaphore is never closed.
+            Some(semaphore) => semaphore.acquire().await.ok(),
+            None => None,
+        };
+
+        drop(queued);
+        self.total_requests.fetch_add(1, Ordering::Relaxed);
+        self.total_wait_nanos.fetch_add(started.elapsed().as_nanos() as u64, Ordering::Relaxed);
+
+        RateLimitPermit { _permit: permit }
+    }
+
+    pub fn stats(&self) -> RateLimiterStats {
+        let available_tokens = self.bucket.as_ref().map(|bucket| {
+            let mut bucket = bucket.lock().unwrap();
+            bucket.refill(Instant::now());
+            bucket.tokens
+        });
+        let in_flight = match (&self.semaphore, self.max_in_flight) {
+            (Some(semaphore), Some(max)) => max - semaphore.available_permits(),
+            _ => 0,
+        };
+
+        RateLimiterStats {
+            queued: self.queued.load(Ordering::SeqCst),
+            in_flight,
+            max_in_flight: self.max_in_flight,
+            available_tokens,
+            total_requests: self.total_requests.load(Ordering::Relaxed),
+            total_wait: Duration::from_nanos(self.total_wait_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_rate_limiter_token_bucket() {
+    let limiter = RateLimiter::new(&RateLimit::new().with_requests_per_second(50.0).with_burst(1)).unwrap();
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        limiter.acquire().await;
+    }
+    // The first request uses the burst, the other two wait 20ms each.
+    assert!(started.elapsed() >= Duration::from_millis(35));
+    assert_eq!(limiter.stats().total_requests, 3);
+}
+
+#[tokio::test]
+async fn test_rate_limiter_dropped_acquire_is_not_queued() {
+    let limiter = RateLimiter::new(&RateLimit::new().with_max_in_flight(1)).unwrap();
+
+    let permit = limiter.acquire().await;
+    let waiting = tokio::time::timeout(Duration::from_millis(10), limiter.acquire()).await;
+    assert!(waiting.is_err());
+    assert_eq!(limiter.stats().queued, 0);
+
+    drop(permit);
+    assert_eq!(limiter.stats().in_flight, 0);
+}
+
+#[tokio::test]
+async fn test_rate_limiter_dropped_acquire_returns_token() {
+    let limiter = RateLimiter::new(&RateLimit::new().with_requests_per_second(1.0).with_burst(1)).unwrap();
+
+    limiter.acquire().await;
+    let waiting = tokio::time::timeout(Duration::from_millis(10), limiter.acquire()).await;
+    assert!(waiting.is_err());
+    assert_eq!(limiter.stats().queued, 0);
+    // Without the returned token the bucket would be close to -1.
+    assert!(limiter.stats().available_tokens.unwrap() > -0.5);
+}
+
+#[test]
+fn test_rate_limiter_rejects_invalid_limits() {
+    assert!(RateLimiter::new(&RateLimit::new().with_requests_per_second(0.0)).is_err());
+    assert!(RateLimiter::new(&RateLimit::new().with_max_in_flight(0)).is_err());
+}
@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::account::*,
+    types::catalog::Plan,
+    types::server::CreateServerRequest,
+    resources::catalog::CatalogOperations,
+    resources::server::ServerOperations,
+    client::Client,
+};
+
+/// Operations for reading account information and managing sub-accounts
+/// and their permissions.
+#[async_trait]
+pub trait AccountOperations {
+    async fn get_account(&self) -> Result<Account, Error>;
+    async fn list_accounts(&self) -> Result<AccountList, Error>;
+    async fn get_account_details(&self, username: &str) -> Result<AccountDetails, Error>;
+    async fn create_sub_account(&self, request: &CreateSubAccountRequest) -> Result<AccountDetails, Error>;
+    async fn modify_account(&self, username: &str, request: &ModifyAccountRequest) -> Result<AccountDetails, Error>;
+    async fn delete_sub_account(&self, username: &str) -> Result<(), Error>;
+    async fn list_permissions(&self) -> Result<PermissionList, Error>;
+    async fn grant_permission(&self, permission: &Permission) -> Result<Permission, Error>;
+    async fn revoke_permission(&self, permission: &Permission) -> Result<(), Error>;
+    /// Checks that creating all of the given servers fits within the core
+    /// and memory limits of the account, taking existing servers into
+    /// account. Returns [`Error::QuotaExceeded`] if it would not, and
+    /// [`Error::ValidationError`] for a plan missing from the catalog.
+    async fn check_server_quota(&self, requests: &[CreateServerRequest]) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl AccountOperations for Client {
+    async fn get_account(&self) -> Result<Account, Error> {
+        let response = self.get("/account").await?;
+        let details: GetAccountResponse = serde_json::from_str(&response)?;
+        Ok(details.account)
+    }
+
+    async fn list_accounts(&self) -> Result<AccountList, Error> {
+        let response = self.get("/account/list").await?;
+        let details: GetAccountListResponse = serde_json::from_str(&response)?;
+        Ok(details.accounts)
+    }
+
+    async fn get_account_details(&self, username: &str) -> Result<AccountDetails, Error> {
+        let response = self.get(&format!("/account/details/{}", username)).await?;
+        let details: GetAccountDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.account)
+    }
+
+    async fn create_sub_account(&self, request: &CreateSubAccountRequest) -> Result<AccountDetails, Error> {
+        let response = self.post("/account/sub", Some(request)).await?;
+        let details: GetAccountDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.account)
+    }
+
+    async fn modify_account(&self, username: &str, request: &ModifyAccountRequest) -> Result<AccountDetails, Error> {
+        let response = self.put(&format!("/account/details/{}", username), Some(request)).await?;
+        let details: GetAccountDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.account)
+    }
+
+    async fn delete_sub_account(&self, username: &str) -> Result<(), Error> {
+        self.delete(&format!("/account/sub/{}", username)).await?;
+        Ok(())
+    }
+
+    async fn list_permissions(&self) -> Result<PermissionList, Error> {
+        let response = self.get("/permission").await?;
+        let details: GetPermissionsResponse = serde_json::from_str(&response)?;
+        Ok(details.permissions)
+    }
+
+    async fn grant_permission(&self, permission: &Permission) -> Result<Permission, Error> {
+        let request = PermissionRequest { permission: permission.clone() };
+        let response = self.post("/permission/grant", Some(&request)).await?;
+        let details: PermissionRequest = serde_json::from_str(&response)?;
+        Ok(details.permission)
+    }
+
+    async fn revoke_permission(&self, permission: &Permission) -> Result<(), Error> {
+        let request = PermissionRequest { permission: permission.clone() };
+        self.post("/permission/revoke", Some(&request)).await?;
+        Ok(())
+    }
+
+    async fn check_server_quota(&self, requests: &[CreateServerRequest]) -> Result<(), Error> {
+        // Plans are only fetched if some request does not set its cores and
+        // memory explicitly.
+        let mut plans: Option<Vec<Plan>> = None;
+        let mut requested_cores = 0;
+        let mut requested_memory = 0;
+        for request in requests {
+            let server = &request.server;
+            let (cores, memory) = match (server.core_number, server.memory_amount) {
+                (Some(cores), Some(memory)) => (cores as i64, memory as i64),
+                _ => {
+                    if plans.is_none() {
+                        plans = Some(self.list_plans().await?.plan);
+                    }
+                    let plan = server.plan.as_deref().unwrap_or_default();
+                    plan_resources(plan, plans.as_deref().unwrap_or_default())?
+                }
+            };
+            requested_cores += cores;
+            requested_memory += memory;
+        }
+
+        let limits = self.get_account().await?.resource_limits;
+
+        let mut used_cores = 0;
+        let mut used_memory = 0;
+        for server in self.list_servers().await?.server {
+            used_cores += server.core_number.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+            used_memory += server.memory_amount.parse::<i64>().unwrap_or(0);
+        }
+
+        let available_cores = limits.cores - used_cores;
+        if requested_cores > available_cores {
+            return Err(Error::QuotaExceeded {
+                resource: "cores".to_string(),
+                requested: requested_cores,
+                available: available_cores.max(0),
+            });
+        }
+
+        let available_memory = limits.memory - used_memory;
+        if requested_memory > available_memory {
+            return Err(Error::QuotaExceeded {
+                resource: "memory".to_string(),
+                requested: requested_memory,
+                available: available_memory.max(0),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// Returns the cores and memory (in megabytes) of a preconfigured plan.
+fn plan_resources(plan: &str, plans: &[Plan]) -> Result<(i64, i64), Error> {
+    plans
+        .iter()
+        .find(|p| p.name == plan)
+        .map(|p| (p.core_number as i64, p.memory_amount as i64))
+        .ok_or_else(|| Error::ValidationError(format!("unknown plan '{}'", plan)))
+}
+
+#[tokio::test]
+async fn test_check_server_quota_exceeded() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _account = server.mock("GET", "/1.3/account")
+        .with_status(200)
+        .with_body(r#"{"account":{"credits":9972.2324,"username":"username","resource_limits":{"cores":8,"memory":16384,"networks":100,"public_ipv4":20,"public_ipv6":100,"storage_hdd":10240,"storage_ssd":10240}}}"#)
+        .create();
+    let _servers = server.mock("GET", "/1.3/server")
+        .with_status(200)
+        .with_body(r#"{"servers":{"server":[{"core_number":"4","hostname":"web1","license":0,"memory_amount":"8192","plan":"4xCPU-8GB","state":"started","tags":{"tag":[]},"title":"web1","uuid":"00798b85-efdc-41ca-8021-f6ef457b8531","zone":"fi-hel1"}]}}"#)
+        .create();
+    let _plans = server.mock("GET", "/1.3/plan")
+        .with_status(200)
+        .with_body(r#"{"plans":{"plan":[{"core_number":2,"memory_amount":4096,"name":"2xCPU-4GB","public_traffic_out":4096,"storage_size":80,"storage_tier":"maxiops"},{"core_number":2,"memory_amount":8192,"name":"HIMEM-2xCPU-8GB","public_traffic_out":2048,"storage_size":100,"storage_tier":"maxiops"}]}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let batch = vec![
+        CreateServerRequest::new().with_plan("2xCPU-4GB").build(),
+        CreateServerRequest::new().with_plan("HIMEM-2xCPU-8GB").build(),
+    ];
+    let err = client.check_server_quota(&batch).await.unwrap_err();
+    match err {
+        Error::QuotaExceeded { resource, requested, available } => {
+            assert_eq!(resource, "memory");
+            assert_eq!(requested, 12288);
+            assert_eq!(available, 8192);
+        }
+        e => panic!("unexpected error: {}", e),
+    }
+
+    client.check_server_quota(&batch[..1]).await.unwrap();
+
+    let unknown = vec![CreateServerRequest::new().with_plan("3xCPU-5GB").build()];
+    let err = client.check_server_quota(&unknown).await.unwrap_err();
+    assert!(matches!(err, Error::ValidationError(_)));
+}
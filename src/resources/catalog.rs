@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::catalog::*,
+    types::server::CreateServerRequest,
+    client::Client,
+};
+
+/// Operations for reading the UpCloud catalog: zones, server plans, prices
+/// and timezones.
+#[async_trait]
+pub trait CatalogOperations {
+    async fn list_zones(&self) -> Result<ZoneList, Error>;
+    async fn list_plans(&self) -> Result<PlanList, Error>;
+    async fn list_prices(&self) -> Result<PriceZoneList, Error>;
+    async fn list_timezones(&self) -> Result<TimezoneList, Error>;
+    /// Checks the zone, plan and timezone of a server request against the
+    /// live catalog. Returns [`Error::ValidationError`] naming the first
+    /// unknown value.
+    async fn validate_create_server_request(&self, request: &CreateServerRequest) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl CatalogOperations for Client {
+    async fn list_zones(&self) -> Result<ZoneList, Error> {
+        let response = self.get("/zone").await?;
+        let details: GetZonesResponse = serde_json::from_str(&response)?;
+        Ok(details.zones)
+    }
+
+    async fn list_plans(&self) -> Result<PlanList, Error> {
+        let response = self.get("/plan").await?;
+        let details: GetPlansResponse = serde_json::from_str(&response)?;
+        Ok(details.plans)
+    }
+
+    async fn list_prices(&self) -> Result<PriceZoneList, Error> {
+        let response = self.get("/price").await?;
+        let details: GetPricesResponse = serde_json::from_str(&response)?;
+        Ok(details.prices)
+    }
+
+    async fn list_timezones(&self) -> Result<TimezoneList, Error> {
+        let response = self.get("/timezone").await?;
+        let details: GetTimezonesResponse = serde_json::from_str(&response)?;
+        Ok(details.timezones)
+    }
+
+    async fn validate_create_server_request(&self, request: &CreateServerRequest) -> Result<(), Error> {
+        let server = &request.server;
+
+        let zones = self.list_zones().await?;
+        if !zones.zone.iter().any(|z| z.id == server.zone) {
+            return Err(Error::ValidationError(format!("unknown zone '{}'", server.zone)));
+        }
+
+        match server.plan.as_deref() {
+            Some(PLAN_CUSTOM) | None => {
+                if server.core_number.is_none() || server.memory_amount.is_none() {
+                    return Err(Error::ValidationError(
+                        "custom plan requires core_number and memory_amount".to_string(),
+                    ));
+                }
+            }
+            Some(plan) => {
+                let plans = self.list_plans().await?;
+                if !plans.plan.iter().any(|p| p.name == plan) {
+                    return Err(Error::ValidationError(format!("unknown plan '{}'", plan)));
+                }
+            }
+        }
+
+        if let Some(timezone) = server.timezone.as_deref() {
+            let timezones = self.list_timezones().await?;
+            if !timezones.timezone.iter().any(|t| t == timezone) {
+                return Err(Error::ValidationError(format!("unknown timezone '{}'", timezone)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_list_prices() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/price")
+        .with_status(200)
+        .with_body(r#"{"prices":{"zone":[{"name":"fi-hel1","firewall":{"amount":1,"price":0.56},"server_plan_1xCPU-1GB":{"amount":1,"price":0.744},"storage_maxiops":{"amount":1,"price":0.031}}]}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let prices = client.list_prices().await.unwrap();
+    let zone = &prices.zone[0];
+    assert_eq!(zone.name, "fi-hel1");
+    assert_eq!(zone.server_plan("1xCPU-1GB").unwrap().price, 0.744);
+    assert_eq!(zone.get("firewall").unwrap().amount, 1);
+}
+
+#[tokio::test]
+async fn test_validate_create_server_request() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _zones = server.mock("GET", "/1.3/zone")
+        .with_status(200)
+        .with_body(r#"{"zones":{"zone":[{"description":"Helsinki #1","id":"fi-hel1","public":"yes"}]}}"#)
+        .create();
+    let _plans = server.mock("GET", "/1.3/plan")
+        .with_status(200)
+        .with_body(r#"{"plans":{"plan":[{"core_number":1,"memory_amount":1024,"name":"1xCPU-1GB","public_traffic_out":1024,"storage_size":25,"storage_tier":"maxiops"}]}}"#)
+        .create();
+    let _timezones = server.mock("GET", "/1.3/timezone")
+        .with_status(200)
+        .with_body(r#"{"timezones":{"timezone":["Europe/Helsinki","UTC"]}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let valid = CreateServerRequest::new()
+        .with_zone("fi-hel1")
+        .with_plan("1xCPU-1GB")
+        .with_timezone("Europe/Helsinki")
+        .build();
+    client.validate_create_server_request(&valid).await.unwrap();
+
+    let invalid = CreateServerRequest::new()
+        .with_zone("fi-hel1")
+        .with_plan("1xCPU-1GB")
+        .with_timezone("Europe/Atlantis")
+        .build();
+    let err = client.validate_create_server_request(&invalid).await.unwrap_err();
+    assert!(matches!(err, Error::ValidationError(ref msg) if msg.contains("Europe/Atlantis")));
+}
@@ -0,0 +1,296 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::database::*,
+    types::common::LabelFilter,
+    client::Client,
+};
+
+use tokio::time::{sleep, Duration};
+
+/// Operations for managing Managed Databases.
+///
+/// This trait provides methods for managing database services and their
+/// logical databases, users and connection pools, and for reading service
+/// metrics and logs.
+#[async_trait]
+pub trait DatabaseOperations {
+    async fn list_databases(&self) -> Result<Vec<ManagedDatabase>, Error>;
+    async fn list_databases_by_labels(&self, filter: &LabelFilter) -> Result<Vec<ManagedDatabase>, Error>;
+    async fn get_database(&self, uuid: &str) -> Result<ManagedDatabase, Error>;
+    async fn create_database(&self, request: &CreateDatabaseRequest) -> Result<ManagedDatabase, Error>;
+    async fn modify_database(&self, uuid: &str, request: &ModifyDatabaseRequest) -> Result<ManagedDatabase, Error>;
+    async fn delete_database(&self, uuid: &str) -> Result<(), Error>;
+    async fn start_database(&self, uuid: &str) -> Result<ManagedDatabase, Error>;
+    async fn stop_database(&self, uuid: &str) -> Result<ManagedDatabase, Error>;
+    async fn list_database_versions(&self, uuid: &str) -> Result<Vec<String>, Error>;
+    async fn upgrade_database_version(&self, uuid: &str, request: &UpgradeDatabaseVersionRequest) -> Result<ManagedDatabase, Error>;
+    async fn list_logical_databases(&self, uuid: &str) -> Result<Vec<DatabaseLogicalDatabase>, Error>;
+    async fn create_logical_database(&self, uuid: &str, request: &DatabaseLogicalDatabase) -> Result<DatabaseLogicalDatabase, Error>;
+    async fn delete_logical_database(&self, uuid: &str, name: &str) -> Result<(), Error>;
+    async fn list_database_users(&self, uuid: &str) -> Result<Vec<DatabaseUser>, Error>;
+    async fn get_database_user(&self, uuid: &str, username: &str) -> Result<DatabaseUser, Error>;
+    async fn create_database_user(&self, uuid: &str, request: &CreateDatabaseUserRequest) -> Result<DatabaseUser, Error>;
+    async fn modify_database_user(&self, uuid: &str, username: &str, request: &ModifyDatabaseUserRequest) -> Result<DatabaseUser, Error>;
+    async fn delete_database_user(&self, uuid: &str, username: &str) -> Result<(), Error>;
+    async fn list_connection_pools(&self, uuid: &str) -> Result<Vec<DatabaseConnectionPool>, Error>;
+    async fn get_connection_pool(&self, uuid: &str, pool_name: &str) -> Result<DatabaseConnectionPool, Error>;
+    async fn create_connection_pool(&self, uuid: &str, request: &DatabaseConnectionPool) -> Result<DatabaseConnectionPool, Error>;
+    async fn modify_connection_pool(&self, uuid: &str, pool_name: &str, request: &ModifyConnectionPoolRequest) -> Result<DatabaseConnectionPool, Error>;
+    async fn delete_connection_pool(&self, uuid: &str, pool_name: &str) -> Result<(), Error>;
+    async fn get_database_metrics(&self, uuid: &str, period: &DatabaseMetricsPeriod) -> Result<DatabaseMetrics, Error>;
+    async fn get_database_logs(&self, uuid: &str, request: &DatabaseLogsRequest) -> Result<DatabaseLogs, Error>;
+    async fn wait_for_database_state(
+        &self,
+        uuid: &str,
+        desired_state: Option<&DatabaseState>,
+        undesired_state: Option<&DatabaseState>,
+        timeout: Duration,
+    ) -> Result<ManagedDatabase, Error>;
+}
+
+#[async_trait]
+impl DatabaseOperations for Client {
+    async fn list_databases(&self) -> Result<Vec<ManagedDatabase>, Error> {
+        let response = self.get("/database").await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn list_databases_by_labels(&self, filter: &LabelFilter) -> Result<Vec<ManagedDatabase>, Error> {
+        let query = filter.to_query_params();
+        let path = if query.is_empty() {
+            "/database".to_string()
+        } else {
+            format!("/database?{}", query)
+        };
+
+        let response = self.get(&path).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_database(&self, uuid: &str) -> Result<ManagedDatabase, Error> {
+        let response = self.get(&format!("/database/{}", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_database(&self, request: &CreateDatabaseRequest) -> Result<ManagedDatabase, Error> {
+        request.validate()?;
+        let response = self.post("/database", Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_database(&self, uuid: &str, request: &ModifyDatabaseRequest) -> Result<ManagedDatabase, Error> {
+        // The request does not carry the engine, so look it up before
+        // sending properties meant for another one.
+        if let Some(properties) = &request.properties {
+            properties.validate_for(&self.get_database(uuid).await?.database_type)?;
+        }
+        let response = self.patch(&format!("/database/{}", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_database(&self, uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/database/{}", uuid)).await?;
+        Ok(())
+    }
+
+    async fn start_database(&self, uuid: &str) -> Result<ManagedDatabase, Error> {
+        self.modify_database(uuid, &ModifyDatabaseRequest::new().with_powered(true)).await
+    }
+
+    async fn stop_database(&self, uuid: &str) -> Result<ManagedDatabase, Error> {
+        self.modify_database(uuid, &ModifyDatabaseRequest::new().with_powered(false)).await
+    }
+
+    async fn list_database_versions(&self, uuid: &str) -> Result<Vec<String>, Error> {
+        let response = self.get(&format!("/database/{}/versions", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn upgrade_database_version(&self, uuid: &str, request: &UpgradeDatabaseVersionRequest) -> Result<ManagedDatabase, Error> {
+        let response = self.post(&format!("/database/{}/upgrade", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn list_logical_databases(&self, uuid: &str) -> Result<Vec<DatabaseLogicalDatabase>, Error> {
+        let response = self.get(&format!("/database/{}/databases", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_logical_database(&self, uuid: &str, request: &DatabaseLogicalDatabase) -> Result<DatabaseLogicalDatabase, Error> {
+        let response = self.post(&format!("/database/{}/databases", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_logical_database(&self, uuid: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/database/{}/databases/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(())
+    }
+
+    async fn list_database_users(&self, uuid: &str) -> Result<Vec<DatabaseUser>, Error> {
+        let response = self.get(&format!("/database/{}/users", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_database_user(&self, uuid: &str, username: &str) -> Result<DatabaseUser, Error> {
+        let response = self.get(&format!("/database/{}/users/{}", uuid, urlencoding::encode(username))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_database_user(&self, uuid: &str, request: &CreateDatabaseUserRequest) -> Result<DatabaseUser, Error> {
+        let response = self.post(&format!("/database/{}/users", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_database_user(&self, uuid: &str, username: &str, request: &ModifyDatabaseUserRequest) -> Result<DatabaseUser, Error> {
+        let response = self.patch(&format!("/database/{}/users/{}", uuid, urlencoding::encode(username)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_database_user(&self, uuid: &str, username: &str) -> Result<(), Error> {
+        self.delete(&format!("/database/{}/users/{}", uuid, urlencoding::encode(username))).await?;
+        Ok(())
+    }
+
+    async fn list_connection_pools(&self, uuid: &str) -> Result<Vec<DatabaseConnectionPool>, Error> {
+        let response = self.get(&format!("/database/{}/connection-pools", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_connection_pool(&self, uuid: &str, pool_name: &str) -> Result<DatabaseConnectionPool, Error> {
+        let response = self.get(&format!("/database/{}/connection-pools/{}", uuid, urlencoding::encode(pool_name))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_connection_pool(&self, uuid: &str, request: &DatabaseConnectionPool) -> Result<DatabaseConnectionPool, Error> {
+        let response = self.post(&format!("/database/{}/connection-pools", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_connection_pool(&self, uuid: &str, pool_name: &str, request: &ModifyConnectionPoolRequest) -> Result<DatabaseConnectionPool, Error> {
+        let response = self.patch(&format!("/database/{}/connection-pools/{}", uuid, urlencoding::encode(pool_name)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_connection_pool(&self, uuid: &str, pool_name: &str) -> Result<(), Error> {
+        self.delete(&format!("/database/{}/connection-pools/{}", uuid, urlencoding::encode(pool_name))).await?;
+        Ok(())
+    }
+
+    async fn get_database_metrics(&self, uuid: &str, period: &DatabaseMetricsPeriod) -> Result<DatabaseMetrics, Error> {
+        let response = self.get(&format!("/database/{}/metrics?period={}", uuid, period.as_str())).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_database_logs(&self, uuid: &str, request: &DatabaseLogsRequest) -> Result<DatabaseLogs, Error> {
+        let response = self.get(&format!("/database/{}/logs?{}", uuid, request.to_query_params())).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn wait_for_database_state(
+        &self,
+        uuid: &str,
+        desired_state: Option<&DatabaseState>,
+        undesired_state: Option<&DatabaseState>,
+        timeout: Duration,
+    ) -> Result<ManagedDatabase, Error> {
+        let start = std::time::Instant::now();
+
+        loop {
+            if start.elapsed() > timeout {
+                return Err(Error::Timeout);
+            }
+
+            let res = self.get_database(uuid).await?;
+
+            match (desired_state, undesired_state) {
+                (Some(desired), _) if res.state == desired.as_str() => return Ok(res),
+                (_, Some(undesired)) if res.state != undesired.as_str() => return Ok(res),
+                _ => {
+                    sleep(Duration::from_secs(5)).await;
+                    continue;
+                }
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_create_database() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _create = server.mock("POST", "/1.3/database")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "type": "pg",
+            "hostname_prefix": "orders",
+            "title": "orders",
+            "plan": "1x1xCPU-2GB-25GB",
+            "zone": "fi-hel1",
+            "properties": {"version": "16", "public_access": false, "ip_filter": ["10.0.0.0/8"]}
+        })))
+        .with_status(201)
+        .with_body(r#"{"uuid":"09352622-5db9-4053-b3f8-a2a4e2d5e4b6","title":"orders","name":"orders-abc","type":"pg","plan":"1x1xCPU-2GB-25GB","zone":"fi-hel1","state":"rebuilding","powered":true,"node_count":1,"node_states":[{"name":"orders-abc-1","role":"master","state":"setting_up_vm"}],"properties":{"version":"16","public_access":false,"ip_filter":["10.0.0.0/8"],"pg_stat_statements_track":"top"},"labels":[]}"#)
+        .create();
+    let _get = server.mock("GET", "/1.3/database/09352622-5db9-4053-b3f8-a2a4e2d5e4b6")
+        .with_status(200)
+        .with_body(r#"{"uuid":"09352622-5db9-4053-b3f8-a2a4e2d5e4b6","title":"orders","type":"pg","plan":"1x1xCPU-2GB-25GB","zone":"fi-hel1","state":"running","powered":true}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let request = CreateDatabaseRequest::new(DatabaseType::Pg, "orders", "1x1xCPU-2GB-25GB", "fi-hel1")
+        .with_properties(PostgresProperties {
+            version: Some("16".to_string()),
+            public_access: Some(false),
+            ip_filter: Some(vec!["10.0.0.0/8".to_string()]),
+            ..Default::default()
+        });
+    let db = client.create_database(&request).await.unwrap();
+    let properties = db.postgres_properties().unwrap();
+    assert_eq!(properties.version.as_deref(), Some("16"));
+    assert_eq!(properties.other["pg_stat_statements_track"], "top");
+
+    let db = client.wait_for_database_state(&db.uuid, Some(&DatabaseState::Running), None, Duration::from_secs(10)).await.unwrap();
+    assert_eq!(db.state, "running");
+}
+
+#[tokio::test]
+async fn test_database_properties_must_match_engine() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let create = server.mock("POST", "/1.3/database")
+        .expect(0)
+        .create();
+    let _get = server.mock("GET", "/1.3/database/09352622-5db9-4053-b3f8-a2a4e2d5e4b6")
+        .with_status(200)
+        .with_body(r#"{"uuid":"09352622-5db9-4053-b3f8-a2a4e2d5e4b6","title":"orders","type":"pg","plan":"1x1xCPU-2GB-25GB","zone":"fi-hel1","state":"running","powered":true}"#)
+        .create();
+    let modify = server.mock("PATCH", "/1.3/database/09352622-5db9-4053-b3f8-a2a4e2d5e4b6")
+        .expect(0)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let request = CreateDatabaseRequest::new(DatabaseType::Pg, "orders", "1x1xCPU-2GB-25GB", "fi-hel1")
+        .with_properties(MysqlProperties::default());
+    let err = client.create_database(&request).await.unwrap_err();
+    assert!(matches!(err, Error::ValidationError(_)));
+
+    let request = ModifyDatabaseRequest::new().with_properties(RedisProperties::default());
+    let err = client.modify_database("09352622-5db9-4053-b3f8-a2a4e2d5e4b6", &request).await.unwrap_err();
+    assert!(matches!(err, Error::ValidationError(_)));
+
+    create.assert();
+    modify.assert();
+}
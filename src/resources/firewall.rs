@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::firewall::*,
+    client::Client,
+};
+
+/// Operations for managing server firewall rules.
+///
+/// Rules are addressed by their position in the server's rule list, which
+/// starts from 1.
+#[async_trait]
+pub trait FirewallOperations {
+    async fn list_firewall_rules(&self, server_uuid: &str) -> Result<FirewallRuleList, Error>;
+    async fn get_firewall_rule(&self, server_uuid: &str, position: i32) -> Result<FirewallRule, Error>;
+    async fn create_firewall_rule(&self, server_uuid: &str, request: &CreateFirewallRuleRequest) -> Result<FirewallRule, Error>;
+    async fn delete_firewall_rule(&self, server_uuid: &str, position: i32) -> Result<(), Error>;
+    async fn replace_firewall_rules(&self, server_uuid: &str, ruleset: &FirewallRuleset) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl FirewallOperations for Client {
+    async fn list_firewall_rules(&self, server_uuid: &str) -> Result<FirewallRuleList, Error> {
+        let response = self.get(&format!("/server/{}/firewall_rule", server_uuid)).await?;
+        let details: GetFirewallRulesResponse = serde_json::from_str(&response)?;
+        Ok(details.firewall_rules)
+    }
+
+    async fn get_firewall_rule(&self, server_uuid: &str, position: i32) -> Result<FirewallRule, Error> {
+        let response = self.get(&format!("/server/{}/firewall_rule/{}", server_uuid, position)).await?;
+        let details: GetFirewallRuleDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.firewall_rule)
+    }
+
+    async fn create_firewall_rule(&self, server_uuid: &str, request: &CreateFirewallRuleRequest) -> Result<FirewallRule, Error> {
+        let response = self.post(&format!("/server/{}/firewall_rule", server_uuid), Some(request)).await?;
+        let details: GetFirewallRuleDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.firewall_rule)
+    }
+
+    async fn delete_firewall_rule(&self, server_uuid: &str, position: i32) -> Result<(), Error> {
+        self.delete(&format!("/server/{}/firewall_rule/{}", server_uuid, position)).await?;
+        Ok(())
+    }
+
+    async fn replace_firewall_rules(&self, server_uuid: &str, ruleset: &FirewallRuleset) -> Result<(), Error> {
+        self.put(&format!("/server/{}/firewall_rule", server_uuid), Some(ruleset)).await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_replace_firewall_rules() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("PUT", "/1.3/server/00798b85-efdc-41ca-8021-f6ef457b8531/firewall_rule")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "firewall_rules": {"firewall_rule": [
+                {"direction":"in","action":"accept","family":"IPv4","protocol":"tcp","destination_port_start":"22","destination_port_end":"22","comment":"SSH"},
+                {"direction":"in","action":"drop"}
+            ]}
+        })))
+        .with_status(204)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let ruleset = FirewallRuleset::new()
+        .with_rule(
+            FirewallRule::new(FirewallRuleDirection::In, FirewallRuleAction::Accept)
+                .with_family(FirewallRuleFamily::IPv4)
+                .with_protocol(FirewallRuleProtocol::Tcp)
+                .with_destination_port(22, 22)
+                .with_comment("SSH")
+        )
+        .with_rule(FirewallRule::new(FirewallRuleDirection::In, FirewallRuleAction::Drop));
+
+    client.replace_firewall_rules("00798b85-efdc-41ca-8021-f6ef457b8531", &ruleset).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_list_firewall_rules() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/server/00798b85-efdc-41ca-8021-f6ef457b8531/firewall_rule")
+        .with_status(200)
+        .with_body(r#"{"firewall_rules":{"firewall_rule":[{"action":"accept","comment":"","destination_address_end":"","destination_address_start":"","destination_port_end":"80","destination_port_start":"80","direction":"in","family":"IPv4","icmp_type":"","position":"1","protocol":"tcp","source_address_end":"","source_address_start":"","source_port_end":"","source_port_start":""}]}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let result = client.list_firewall_rules("00798b85-efdc-41ca-8021-f6ef457b8531").await.unwrap();
+    assert_eq!(result.firewall_rule.len(), 1);
+    assert_eq!(result.firewall_rule[0].protocol, Some(FirewallRuleProtocol::Tcp));
+    assert_eq!(result.firewall_rule[0].destination_port_start.as_deref(), Some("80"));
+    assert_eq!(result.firewall_rule[0].comment, None);
+}
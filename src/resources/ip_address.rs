@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::ip_address::*,
+    types::server::IPAddress,
+    types::common::Page,
+    types::network::NETWORK_TYPE_PUBLIC,
+    resources::network::NetworkOperations,
+    client::Client,
+    pagination::paginate,
+};
+
+use futures::stream::{BoxStream, StreamExt};
+
+/// Operations for managing IP addresses.
+///
+/// This trait provides methods for assigning, modifying and releasing IP
+/// addresses, and for moving floating IP addresses between servers.
+#[async_trait]
+pub trait IpAddressOperations {
+    async fn list_ip_addresses(&self) -> Result<IPAddressList, Error>;
+    async fn list_ip_addresses_page(&self, page: &Page) -> Result<IPAddressList, Error>;
+    /// Streams all IP addresses, fetching `page_size` addresses per request.
+    fn stream_ip_addresses(&self, page_size: u32) -> BoxStream<'_, Result<IPAddress, Error>>;
+    async fn get_ip_address(&self, address: &str) -> Result<IPAddress, Error>;
+    async fn assign_ip_address(&self, request: &AssignIPAddressRequest) -> Result<IPAddress, Error>;
+    async fn modify_ip_address(&self, address: &str, request: &ModifyIPAddressRequest) -> Result<IPAddress, Error>;
+    async fn release_ip_address(&self, address: &str) -> Result<(), Error>;
+    async fn move_floating_ip_address(&self, address: &str, server_uuid: &str) -> Result<IPAddress, Error>;
+    async fn detach_floating_ip_address(&self, address: &str) -> Result<IPAddress, Error>;
+}
+
+#[async_trait]
+impl IpAddressOperations for Client {
+    async fn list_ip_addresses(&self) -> Result<IPAddressList, Error> {
+        let response = self.get("/ip_address").await?;
+        let details: GetIPAddressesResponse = serde_json::from_str(&response)?;
+        Ok(details.ip_addresses)
+    }
+
+    async fn list_ip_addresses_page(&self, page: &Page) -> Result<IPAddressList, Error> {
+        let response = self.get(&format!("/ip_address?{}", page.to_query_params())).await?;
+        let details: GetIPAddressesResponse = serde_json::from_str(&response)?;
+        Ok(details.ip_addresses)
+    }
+
+    fn stream_ip_addresses(&self, page_size: u32) -> BoxStream<'_, Result<IPAddress, Error>> {
+        paginate(page_size, move |page| async move {
+            Ok(self.list_ip_addresses_page(&page).await?.ip_address)
+        })
+        .boxed()
+    }
+
+    async fn get_ip_address(&self, address: &str) -> Result<IPAddress, Error> {
+        let response = self.get(&format!("/ip_address/{}", address)).await?;
+        let details: GetIPAddressDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.ip_address)
+    }
+
+    async fn assign_ip_address(&self, request: &AssignIPAddressRequest) -> Result<IPAddress, Error> {
+        let response = self.post("/ip_address", Some(request)).await?;
+        let details: GetIPAddressDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.ip_address)
+    }
+
+    async fn modify_ip_address(&self, address: &str, request: &ModifyIPAddressRequest) -> Result<IPAddress, Error> {
+        let response = self.put(&format!("/ip_address/{}", address), Some(request)).await?;
+        let details: GetIPAddressDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.ip_address)
+    }
+
+    async fn release_ip_address(&self, address: &str) -> Result<(), Error> {
+        self.delete(&format!("/ip_address/{}", address)).await?;
+        Ok(())
+    }
+
+    async fn move_floating_ip_address(&self, address: &str, server_uuid: &str) -> Result<IPAddress, Error> {
+        // Floating IPs are attached to a network interface by MAC address, so
+        // look up the public IPv4 interface of the target server first.
+        let mac = self.get_server_networking(server_uuid).await?
+            .interfaces
+            .into_iter()
+            .find(|interface| {
+                interface.interface_type.as_deref() == Some(NETWORK_TYPE_PUBLIC)
+                    && interface.ip_addresses.iter().flatten().any(|ip| {
+                        ip.family == IP_ADDRESS_FAMILY_IPV4 && ip.floating != Some(true)
+                    })
+            })
+            .and_then(|interface| interface.mac)
+            .ok_or_else(|| Error::ValidationError(format!("server {} has no public IPv4 interface", server_uuid)))?;
+
+        self.modify_ip_address(address, &ModifyIPAddressRequest::new().with_mac(mac)).await
+    }
+
+    async fn detach_floating_ip_address(&self, address: &str) -> Result<IPAddress, Error> {
+        self.modify_ip_address(address, &ModifyIPAddressRequest::new().with_mac("")).await
+    }
+}
+
+#[tokio::test]
+async fn test_move_floating_ip_address() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _networking = server.mock("GET", "/1.3/server/0053cd80-5945-4105-9081-11192806a8f7/networking")
+        .with_status(200)
+        .with_body(r#"{"networking":{"interfaces":{"interface":[{"index":1,"ip_addresses":{"ip_address":[{"address":"10.3.4.5","family":"IPv4","floating":"no"}]},"mac":"de:ff:ff:ff:55:10","network":"03c93130-c9d1-4a1f-8fb6-de7b7f2b6e8c","type":"utility"},{"index":2,"ip_addresses":{"ip_address":[{"address":"94.237.0.207","family":"IPv4","floating":"no"}]},"mac":"de:ff:ff:ff:66:89","network":"037fcf2a-6745-45dd-867e-f9479ea8c044","type":"public"}]}}}"#)
+        .create();
+    let _modify = server.mock("PUT", "/1.3/ip_address/94.237.0.254")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"ip_address":{"mac":"de:ff:ff:ff:66:89"}})))
+        .with_status(202)
+        .with_body(r#"{"ip_address":{"access":"public","address":"94.237.0.254","family":"IPv4","floating":"yes","mac":"de:ff:ff:ff:66:89","ptr_record":"","server":"0053cd80-5945-4105-9081-11192806a8f7","zone":"fi-hel1"}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let result = client.move_floating_ip_address("94.237.0.254", "0053cd80-5945-4105-9081-11192806a8f7").await.unwrap();
+    assert_eq!(result.floating, Some(true));
+    assert_eq!(result.server, "0053cd80-5945-4105-9081-11192806a8f7");
+}
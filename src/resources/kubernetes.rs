@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::kubernetes::*,
+    client::Client,
+};
+
+/// Operations for managing UpCloud Kubernetes Service (UKS) clusters.
+///
+/// This trait provides methods for managing clusters and their node groups,
+/// listing available plans and versions, and fetching cluster kubeconfigs.
+#[async_trait]
+pub trait KubernetesOperations {
+    async fn list_kubernetes_clusters(&self) -> Result<Vec<KubernetesCluster>, Error>;
+    async fn get_kubernetes_cluster(&self, uuid: &str) -> Result<KubernetesCluster, Error>;
+    async fn create_kubernetes_cluster(&self, request: &CreateKubernetesClusterRequest) -> Result<KubernetesCluster, Error>;
+    async fn modify_kubernetes_cluster(&self, uuid: &str, request: &ModifyKubernetesClusterRequest) -> Result<KubernetesCluster, Error>;
+    async fn delete_kubernetes_cluster(&self, uuid: &str) -> Result<(), Error>;
+    async fn list_kubernetes_node_groups(&self, uuid: &str) -> Result<Vec<KubernetesNodeGroup>, Error>;
+    async fn get_kubernetes_node_group(&self, uuid: &str, name: &str) -> Result<KubernetesNodeGroup, Error>;
+    async fn create_kubernetes_node_group(&self, uuid: &str, request: &CreateKubernetesNodeGroupRequest) -> Result<KubernetesNodeGroup, Error>;
+    async fn modify_kubernetes_node_group(&self, uuid: &str, name: &str, request: &ModifyKubernetesNodeGroupRequest) -> Result<KubernetesNodeGroup, Error>;
+    async fn scale_kubernetes_node_group(&self, uuid: &str, name: &str, count: i32) -> Result<KubernetesNodeGroup, Error>;
+    async fn delete_kubernetes_node_group(&self, uuid: &str, name: &str) -> Result<(), Error>;
+    async fn list_kubernetes_plans(&self) -> Result<Vec<KubernetesPlan>, Error>;
+    async fn list_kubernetes_versions(&self) -> Result<Vec<KubernetesVersion>, Error>;
+    async fn get_kubernetes_kubeconfig_yaml(&self, uuid: &str) -> Result<String, Error>;
+    #[cfg(feature = "kubeconfig")]
+    async fn get_kubernetes_kubeconfig(&self, uuid: &str) -> Result<Kubeconfig, Error>;
+}
+
+#[async_trait]
+impl KubernetesOperations for Client {
+    async fn list_kubernetes_clusters(&self) -> Result<Vec<KubernetesCluster>, Error> {
+        let response = self.get("/kubernetes").await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_kubernetes_cluster(&self, uuid: &str) -> Result<KubernetesCluster, Error> {
+        let response = self.get(&format!("/kubernetes/{}", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_kubernetes_cluster(&self, request: &CreateKubernetesClusterRequest) -> Result<KubernetesCluster, Error> {
+        let response = self.post("/kubernetes", Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_kubernetes_cluster(&self, uuid: &str, request: &ModifyKubernetesClusterRequest) -> Result<KubernetesCluster, Error> {
+        let response = self.patch(&format!("/kubernetes/{}", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_kubernetes_cluster(&self, uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/kubernetes/{}", uuid)).await?;
+        Ok(())
+    }
+
+    async fn list_kubernetes_node_groups(&self, uuid: &str) -> Result<Vec<KubernetesNodeGroup>, Error> {
+        let response = self.get(&format!("/kubernetes/{}/node-groups", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_kubernetes_node_group(&self, uuid: &str, name: &str) -> Result<KubernetesNodeGroup, Error> {
+        let response = self.get(&format!("/kubernetes/{}/node-groups/{}", uuid, name)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_kubernetes_node_group(&self, uuid: &str, request: &CreateKubernetesNodeGroupRequest) -> Result<KubernetesNodeGroup, Error> {
+        let response = self.post(&format!("/kubernetes/{}/node-groups", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_kubernetes_node_group(&self, uuid: &str, name: &str, request: &ModifyKubernetesNodeGroupRequest) -> Result<KubernetesNodeGroup, Error> {
+        let response = self.patch(&format!("/kubernetes/{}/node-groups/{}", uuid, name), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn scale_kubernetes_node_group(&self, uuid: &str, name: &str, count: i32) -> Result<KubernetesNodeGroup, Error> {
+        self.modify_kubernetes_node_group(uuid, name, &ModifyKubernetesNodeGroupRequest::new(count)).await
+    }
+
+    async fn delete_kubernetes_node_group(&self, uuid: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/kubernetes/{}/node-groups/{}", uuid, name)).await?;
+        Ok(())
+    }
+
+    async fn list_kubernetes_plans(&self) -> Result<Vec<KubernetesPlan>, Error> {
+        let response = self.get("/kubernetes/plans").await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn list_kubernetes_versions(&self) -> Result<Vec<KubernetesVersion>, Error> {
+        let response = self.get("/kubernetes/versions").await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_kubernetes_kubeconfig_yaml(&self, uuid: &str) -> Result<String, Error> {
+        let response = self.get(&format!("/kubernetes/{}/kubeconfig", uuid)).await?;
+        let details: GetKubeconfigResponse = serde_json::from_str(&response)?;
+        Ok(details.kubeconfig)
+    }
+
+    #[cfg(feature = "kubeconfig")]
+    async fn get_kubernetes_kubeconfig(&self, uuid: &str) -> Result<Kubeconfig, Error> {
+        let kubeconfig = self.get_kubernetes_kubeconfig_yaml(uuid).await?;
+        Ok(serde_norway::from_str(&kubeconfig)?)
+    }
+}
+
+#[tokio::test]
+async fn test_get_kubernetes_kubeconfig() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/kubernetes/0ddab8f4-97c0-4222-91ba-85a4fff7499b/kubeconfig")
+        .with_status(200)
+        .with_body(serde_json::json!({
+            "kubeconfig": "apiVersion: v1\nkind: Config\nclusters:\n- cluster:\n    certificate-authority-data: Q0E=\n    server: https://lb-0ddab8f4.k8s.upcloud.com:6443\n  name: example\ncontexts:\n- context:\n    cluster: example\n    user: example-admin\n  name: example-admin@example\ncurrent-context: example-admin@example\nusers:\n- name: example-admin\n  user:\n    client-certificate-data: Q0VSVA==\n    client-key-data: S0VZ\n"
+        }).to_string())
+        .expect(if cfg!(feature = "kubeconfig") { 2 } else { 1 })
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let raw = client.get_kubernetes_kubeconfig_yaml("0ddab8f4-97c0-4222-91ba-85a4fff7499b").await.unwrap();
+    assert!(raw.starts_with("apiVersion: v1"));
+
+    #[cfg(feature = "kubeconfig")]
+    {
+        let kubeconfig = client.get_kubernetes_kubeconfig("0ddab8f4-97c0-4222-91ba-85a4fff7499b").await.unwrap();
+        assert_eq!(kubeconfig.current_context, "example-admin@example");
+        assert_eq!(kubeconfig.clusters[0].cluster.server, "https://lb-0ddab8f4.k8s.upcloud.com:6443");
+        assert_eq!(kubeconfig.users[0].user.client_key_data.as_deref(), Some("S0VZ"));
+        assert!(!format!("{:?}", kubeconfig).contains("S0VZ"));
+    }
+}
+
+#[tokio::test]
+async fn test_scale_kubernetes_node_group() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("PATCH", "/1.3/kubernetes/0ddab8f4-97c0-4222-91ba-85a4fff7499b/node-groups/workers")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"count": 5})))
+        .with_status(200)
+        .with_body(r#"{"name":"workers","count":5,"plan":"2xCPU-4GB","state":"scaling-up","labels":[],"taints":[{"key":"dedicated","value":"gpu","effect":"NoSchedule"}],"anti_affinity":false}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let result = client.scale_kubernetes_node_group("0ddab8f4-97c0-4222-91ba-85a4fff7499b", "workers", 5).await.unwrap();
+    assert_eq!(result.count, 5);
+    assert_eq!(result.taints[0].effect, Some(KubernetesTaintEffect::NoSchedule));
+}
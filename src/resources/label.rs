@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::common::{LabelListBody, Labels, LabelsBody, LabelsWrapper, LabelledResource},
+    client::Client,
+};
+
+/// Operations for managing the labels of any labelled resource.
+///
+/// Labels are validated before they are sent. Adding or removing labels
+/// reads the current labels first and then replaces them.
+#[async_trait]
+pub trait LabelOperations {
+    async fn get_labels(&self, resource: LabelledResource, uuid: &str) -> Result<Labels, Error>;
+    async fn replace_labels(&self, resource: LabelledResource, uuid: &str, labels: &Labels) -> Result<Labels, Error>;
+    /// Adds the given labels, overwriting the values of existing keys.
+    async fn add_labels(&self, resource: LabelledResource, uuid: &str, labels: &Labels) -> Result<Labels, Error>;
+    async fn remove_labels(&self, resource: LabelledResource, uuid: &str, keys: &[&str]) -> Result<Labels, Error>;
+}
+
+#[async_trait]
+impl LabelOperations for Client {
+    async fn get_labels(&self, resource: LabelledResource, uuid: &str) -> Result<Labels, Error> {
+        let response = self.get(&format!("/{}/{}", resource.as_str(), uuid)).await?;
+        labels_from_response(resource, &response)
+    }
+
+    async fn replace_labels(&self, resource: LabelledResource, uuid: &str, labels: &Labels) -> Result<Labels, Error> {
+        labels.validate()?;
+
+        let path = format!("/{}/{}", resource.as_str(), uuid);
+        let body = LabelsBody { labels: Some(labels.clone()) };
+        let response = match resource {
+            LabelledResource::Server => self.put(&path, Some(&LabelsWrapper::Server(body))).await?,
+            LabelledResource::Storage => self.put(&path, Some(&LabelsWrapper::Storage(body))).await?,
+            LabelledResource::Network => self.put(&path, Some(&LabelsWrapper::Network(body))).await?,
+            LabelledResource::Router => self.patch(&path, Some(&LabelsWrapper::Router(body))).await?,
+            LabelledResource::LoadBalancer | LabelledResource::Database => {
+                let body = LabelListBody { labels: Some(labels.label.clone()) };
+                self.patch(&path, Some(&body)).await?
+            }
+        };
+        labels_from_response(resource, &response)
+    }
+
+    async fn add_labels(&self, resource: LabelledResource, uuid: &str, labels: &Labels) -> Result<Labels, Error> {
+        let mut current = self.get_labels(resource, uuid).await?;
+        for label in &labels.label {
+            current.set(&label.key, &label.value);
+        }
+        self.replace_labels(resource, uuid, &current).await
+    }
+
+    async fn remove_labels(&self, resource: LabelledResource, uuid: &str, keys: &[&str]) -> Result<Labels, Error> {
+        let mut current = self.get_labels(resource, uuid).await?;
+        for key in keys {
+            current.remove(key);
+        }
+        self.replace_labels(resource, uuid, &current).await
+    }
+}
+
+// API 1.3 resources wrap the object and its labels, e.g.
+// `{"server":{"labels":{"label":[..]}}}`, while newer endpoints return a
+// bare object with a flat label list.
+fn labels_from_response(resource: LabelledResource, response: &str) -> Result<Labels, Error> {
+    let labels = match resource {
+        LabelledResource::LoadBalancer | LabelledResource::Database => {
+            let body: LabelListBody = serde_json::from_str(response)?;
+            body.labels.map(|label| Labels { label })
+        }
+        _ => match serde_json::from_str(response)? {
+            LabelsWrapper::Server(body)
+            | LabelsWrapper::Storage(body)
+            | LabelsWrapper::Network(body)
+            | LabelsWrapper::Router(body) => body.labels,
+        },
+    };
+    Ok(labels.unwrap_or_default())
+}
+
+#[tokio::test]
+async fn test_add_labels_to_storage() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _get = server.mock("GET", "/1.3/storage/01d4fcd4-e446-433b-8a9c-551a1284952e")
+        .with_status(200)
+        .with_body(r#"{"storage":{"access":"private","labels":{"label":[{"key":"env","value":"dev"},{"key":"team","value":"web"}]},"size":10,"state":"online","title":"data","type":"normal","uuid":"01d4fcd4-e446-433b-8a9c-551a1284952e"}}"#)
+        .create();
+    let _put = server.mock("PUT", "/1.3/storage/01d4fcd4-e446-433b-8a9c-551a1284952e")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "storage": {"labels": {"label": [
+                {"key": "env", "value": "prod"},
+                {"key": "team", "value": "web"},
+                {"key": "backup", "value": "daily"}
+            ]}}
+        })))
+        .with_status(202)
+        .with_body(r#"{"storage":{"access":"private","labels":{"label":[{"key":"env","value":"prod"},{"key":"team","value":"web"},{"key":"backup","value":"daily"}]},"size":10,"state":"online","title":"data","type":"normal","uuid":"01d4fcd4-e446-433b-8a9c-551a1284952e"}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let labels = client.add_labels(
+        LabelledResource::Storage,
+        "01d4fcd4-e446-433b-8a9c-551a1284952e",
+        &Labels::new().with("env", "prod").with("backup", "daily"),
+    ).await.unwrap();
+    assert_eq!(labels.label.len(), 3);
+    assert_eq!(labels.get("env"), Some("prod"));
+}
+
+#[tokio::test]
+async fn test_remove_labels_from_database() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _get = server.mock("GET", "/1.3/database/09352622-5db9-4053-b3ec-a5e12a9a8be7")
+        .with_status(200)
+        .with_body(r#"{"labels":[{"key":"env","value":"prod"},{"key":"tmp","value":"yes"}]}"#)
+        .create();
+    let _patch = server.mock("PATCH", "/1.3/database/09352622-5db9-4053-b3ec-a5e12a9a8be7")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"labels": [{"key": "env", "value": "prod"}]})))
+        .with_status(200)
+        .with_body(r#"{"labels":[{"key":"env","value":"prod"}]}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let labels = client.remove_labels(LabelledResource::Database, "09352622-5db9-4053-b3ec-a5e12a9a8be7", &["tmp"]).await.unwrap();
+    assert_eq!(labels.label.len(), 1);
+}
@@ -0,0 +1,385 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::load_balancer::*,
+    types::common::LabelFilter,
+    client::Client,
+};
+
+/// Operations for managing Managed Load Balancers.
+///
+/// Frontends, backends, resolvers and TLS configurations are addressed by
+/// their name within a load balancer; certificate bundles are shared by all
+/// load balancers of the account and addressed by UUID.
+#[async_trait]
+pub trait LoadBalancerOperations {
+    async fn list_load_balancers(&self) -> Result<Vec<LoadBalancer>, Error>;
+    async fn list_load_balancers_by_labels(&self, filter: &LabelFilter) -> Result<Vec<LoadBalancer>, Error>;
+    async fn get_load_balancer(&self, uuid: &str) -> Result<LoadBalancer, Error>;
+    async fn create_load_balancer(&self, request: &CreateLoadBalancerRequest) -> Result<LoadBalancer, Error>;
+    async fn modify_load_balancer(&self, uuid: &str, request: &ModifyLoadBalancerRequest) -> Result<LoadBalancer, Error>;
+    async fn delete_load_balancer(&self, uuid: &str) -> Result<(), Error>;
+    async fn list_load_balancer_frontends(&self, uuid: &str) -> Result<Vec<LoadBalancerFrontend>, Error>;
+    async fn get_load_balancer_frontend(&self, uuid: &str, name: &str) -> Result<LoadBalancerFrontend, Error>;
+    async fn create_load_balancer_frontend(&self, uuid: &str, request: &CreateFrontendRequest) -> Result<LoadBalancerFrontend, Error>;
+    async fn modify_load_balancer_frontend(&self, uuid: &str, name: &str, request: &ModifyFrontendRequest) -> Result<LoadBalancerFrontend, Error>;
+    async fn delete_load_balancer_frontend(&self, uuid: &str, name: &str) -> Result<(), Error>;
+    async fn list_load_balancer_rules(&self, uuid: &str, frontend: &str) -> Result<Vec<LoadBalancerRule>, Error>;
+    async fn get_load_balancer_rule(&self, uuid: &str, frontend: &str, name: &str) -> Result<LoadBalancerRule, Error>;
+    async fn create_load_balancer_rule(&self, uuid: &str, frontend: &str, request: &CreateRuleRequest) -> Result<LoadBalancerRule, Error>;
+    async fn modify_load_balancer_rule(&self, uuid: &str, frontend: &str, name: &str, request: &ModifyRuleRequest) -> Result<LoadBalancerRule, Error>;
+    async fn replace_load_balancer_rule(&self, uuid: &str, frontend: &str, name: &str, request: &CreateRuleRequest) -> Result<LoadBalancerRule, Error>;
+    async fn delete_load_balancer_rule(&self, uuid: &str, frontend: &str, name: &str) -> Result<(), Error>;
+    async fn list_load_balancer_frontend_tls_configs(&self, uuid: &str, frontend: &str) -> Result<Vec<LoadBalancerFrontendTLSConfig>, Error>;
+    async fn create_load_balancer_frontend_tls_config(&self, uuid: &str, frontend: &str, request: &CreateFrontendTLSConfigRequest) -> Result<LoadBalancerFrontendTLSConfig, Error>;
+    async fn delete_load_balancer_frontend_tls_config(&self, uuid: &str, frontend: &str, name: &str) -> Result<(), Error>;
+    async fn list_load_balancer_backends(&self, uuid: &str) -> Result<Vec<LoadBalancerBackend>, Error>;
+    async fn get_load_balancer_backend(&self, uuid: &str, name: &str) -> Result<LoadBalancerBackend, Error>;
+    async fn create_load_balancer_backend(&self, uuid: &str, request: &CreateBackendRequest) -> Result<LoadBalancerBackend, Error>;
+    async fn modify_load_balancer_backend(&self, uuid: &str, name: &str, request: &ModifyBackendRequest) -> Result<LoadBalancerBackend, Error>;
+    async fn delete_load_balancer_backend(&self, uuid: &str, name: &str) -> Result<(), Error>;
+    async fn list_load_balancer_backend_members(&self, uuid: &str, backend: &str) -> Result<Vec<LoadBalancerBackendMember>, Error>;
+    async fn get_load_balancer_backend_member(&self, uuid: &str, backend: &str, name: &str) -> Result<LoadBalancerBackendMember, Error>;
+    async fn create_load_balancer_backend_member(&self, uuid: &str, backend: &str, request: &CreateBackendMemberRequest) -> Result<LoadBalancerBackendMember, Error>;
+    async fn modify_load_balancer_backend_member(&self, uuid: &str, backend: &str, name: &str, request: &ModifyBackendMemberRequest) -> Result<LoadBalancerBackendMember, Error>;
+    async fn delete_load_balancer_backend_member(&self, uuid: &str, backend: &str, name: &str) -> Result<(), Error>;
+    async fn list_load_balancer_resolvers(&self, uuid: &str) -> Result<Vec<LoadBalancerResolver>, Error>;
+    async fn get_load_balancer_resolver(&self, uuid: &str, name: &str) -> Result<LoadBalancerResolver, Error>;
+    async fn create_load_balancer_resolver(&self, uuid: &str, request: &CreateResolverRequest) -> Result<LoadBalancerResolver, Error>;
+    async fn modify_load_balancer_resolver(&self, uuid: &str, name: &str, request: &ModifyResolverRequest) -> Result<LoadBalancerResolver, Error>;
+    async fn delete_load_balancer_resolver(&self, uuid: &str, name: &str) -> Result<(), Error>;
+    async fn list_load_balancer_certificate_bundles(&self) -> Result<Vec<LoadBalancerCertificateBundle>, Error>;
+    async fn get_load_balancer_certificate_bundle(&self, uuid: &str) -> Result<LoadBalancerCertificateBundle, Error>;
+    async fn create_load_balancer_certificate_bundle(&self, request: &CreateCertificateBundleRequest) -> Result<LoadBalancerCertificateBundle, Error>;
+    async fn modify_load_balancer_certificate_bundle(&self, uuid: &str, request: &ModifyCertificateBundleRequest) -> Result<LoadBalancerCertificateBundle, Error>;
+    async fn delete_load_balancer_certificate_bundle(&self, uuid: &str) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl LoadBalancerOperations for Client {
+    async fn list_load_balancers(&self) -> Result<Vec<LoadBalancer>, Error> {
+        let response = self.get("/load-balancer").await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn list_load_balancers_by_labels(&self, filter: &LabelFilter) -> Result<Vec<LoadBalancer>, Error> {
+        let query = filter.to_query_params();
+        let path = if query.is_empty() {
+            "/load-balancer".to_string()
+        } else {
+            format!("/load-balancer?{}", query)
+        };
+
+        let response = self.get(&path).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_load_balancer(&self, uuid: &str) -> Result<LoadBalancer, Error> {
+        let response = self.get(&format!("/load-balancer/{}", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_load_balancer(&self, request: &CreateLoadBalancerRequest) -> Result<LoadBalancer, Error> {
+        let response = self.post("/load-balancer", Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_load_balancer(&self, uuid: &str, request: &ModifyLoadBalancerRequest) -> Result<LoadBalancer, Error> {
+        let response = self.patch(&format!("/load-balancer/{}", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_load_balancer(&self, uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/load-balancer/{}", uuid)).await?;
+        Ok(())
+    }
+
+    async fn list_load_balancer_frontends(&self, uuid: &str) -> Result<Vec<LoadBalancerFrontend>, Error> {
+        let response = self.get(&format!("/load-balancer/{}/frontends", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_load_balancer_frontend(&self, uuid: &str, name: &str) -> Result<LoadBalancerFrontend, Error> {
+        let response = self.get(&format!("/load-balancer/{}/frontends/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_load_balancer_frontend(&self, uuid: &str, request: &CreateFrontendRequest) -> Result<LoadBalancerFrontend, Error> {
+        let response = self.post(&format!("/load-balancer/{}/frontends", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_load_balancer_frontend(&self, uuid: &str, name: &str, request: &ModifyFrontendRequest) -> Result<LoadBalancerFrontend, Error> {
+        let response = self.patch(&format!("/load-balancer/{}/frontends/{}", uuid, urlencoding::encode(name)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_load_balancer_frontend(&self, uuid: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/load-balancer/{}/frontends/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(())
+    }
+
+    async fn list_load_balancer_rules(&self, uuid: &str, frontend: &str) -> Result<Vec<LoadBalancerRule>, Error> {
+        let response = self.get(&format!("/load-balancer/{}/frontends/{}/rules", uuid, urlencoding::encode(frontend))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_load_balancer_rule(&self, uuid: &str, frontend: &str, name: &str) -> Result<LoadBalancerRule, Error> {
+        let response = self.get(&format!("/load-balancer/{}/frontends/{}/rules/{}", uuid, urlencoding::encode(frontend), urlencoding::encode(name))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_load_balancer_rule(&self, uuid: &str, frontend: &str, request: &CreateRuleRequest) -> Result<LoadBalancerRule, Error> {
+        let response = self.post(&format!("/load-balancer/{}/frontends/{}/rules", uuid, urlencoding::encode(frontend)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_load_balancer_rule(&self, uuid: &str, frontend: &str, name: &str, request: &ModifyRuleRequest) -> Result<LoadBalancerRule, Error> {
+        let response = self.patch(&format!("/load-balancer/{}/frontends/{}/rules/{}", uuid, urlencoding::encode(frontend), urlencoding::encode(name)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn replace_load_balancer_rule(&self, uuid: &str, frontend: &str, name: &str, request: &CreateRuleRequest) -> Result<LoadBalancerRule, Error> {
+        let response = self.put(&format!("/load-balancer/{}/frontends/{}/rules/{}", uuid, urlencoding::encode(frontend), urlencoding::encode(name)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_load_balancer_rule(&self, uuid: &str, frontend: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/load-balancer/{}/frontends/{}/rules/{}", uuid, urlencoding::encode(frontend), urlencoding::encode(name))).await?;
+        Ok(())
+    }
+
+    async fn list_load_balancer_frontend_tls_configs(&self, uuid: &str, frontend: &str) -> Result<Vec<LoadBalancerFrontendTLSConfig>, Error> {
+        let response = self.get(&format!("/load-balancer/{}/frontends/{}/tls-configs", uuid, urlencoding::encode(frontend))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_load_balancer_frontend_tls_config(&self, uuid: &str, frontend: &str, request: &CreateFrontendTLSConfigRequest) -> Result<LoadBalancerFrontendTLSConfig, Error> {
+        let response = self.post(&format!("/load-balancer/{}/frontends/{}/tls-configs", uuid, urlencoding::encode(frontend)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_load_balancer_frontend_tls_config(&self, uuid: &str, frontend: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/load-balancer/{}/frontends/{}/tls-configs/{}", uuid, urlencoding::encode(frontend), urlencoding::encode(name))).await?;
+        Ok(())
+    }
+
+    async fn list_load_balancer_backends(&self, uuid: &str) -> Result<Vec<LoadBalancerBackend>, Error> {
+        let response = self.get(&format!("/load-balancer/{}/backends", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_load_balancer_backend(&self, uuid: &str, name: &str) -> Result<LoadBalancerBackend, Error> {
+        let response = self.get(&format!("/load-balancer/{}/backends/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_load_balancer_backend(&self, uuid: &str, request: &CreateBackendRequest) -> Result<LoadBalancerBackend, Error> {
+        let response = self.post(&format!("/load-balancer/{}/backends", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_load_balancer_backend(&self, uuid: &str, name: &str, request: &ModifyBackendRequest) -> Result<LoadBalancerBackend, Error> {
+        let response = self.patch(&format!("/load-balancer/{}/backends/{}", uuid, urlencoding::encode(name)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_load_balancer_backend(&self, uuid: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/load-balancer/{}/backends/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(())
+    }
+
+    async fn list_load_balancer_backend_members(&self, uuid: &str, backend: &str) -> Result<Vec<LoadBalancerBackendMember>, Error> {
+        let response = self.get(&format!("/load-balancer/{}/backends/{}/members", uuid, urlencoding::encode(backend))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_load_balancer_backend_member(&self, uuid: &str, backend: &str, name: &str) -> Result<LoadBalancerBackendMember, Error> {
+        let response = self.get(&format!("/load-balancer/{}/backends/{}/members/{}", uuid, urlencoding::encode(backend), urlencoding::encode(name))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_load_balancer_backend_member(&self, uuid: &str, backend: &str, request: &CreateBackendMemberRequest) -> Result<LoadBalancerBackendMember, Error> {
+        let response = self.post(&format!("/load-balancer/{}/backends/{}/members", uuid, urlencoding::encode(backend)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_load_balancer_backend_member(&self, uuid: &str, backend: &str, name: &str, request: &ModifyBackendMemberRequest) -> Result<LoadBalancerBackendMember, Error> {
+        let response = self.patch(&format!("/load-balancer/{}/backends/{}/members/{}", uuid, urlencoding::encode(backend), urlencoding::encode(name)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_load_balancer_backend_member(&self, uuid: &str, backend: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/load-balancer/{}/backends/{}/members/{}", uuid, urlencoding::encode(backend), urlencoding::encode(name))).await?;
+        Ok(())
+    }
+
+    async fn list_load_balancer_resolvers(&self, uuid: &str) -> Result<Vec<LoadBalancerResolver>, Error> {
+        let response = self.get(&format!("/load-balancer/{}/resolvers", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_load_balancer_resolver(&self, uuid: &str, name: &str) -> Result<LoadBalancerResolver, Error> {
+        let response = self.get(&format!("/load-balancer/{}/resolvers/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_load_balancer_resolver(&self, uuid: &str, request: &CreateResolverRequest) -> Result<LoadBalancerResolver, Error> {
+        let response = self.post(&format!("/load-balancer/{}/resolvers", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_load_balancer_resolver(&self, uuid: &str, name: &str, request: &ModifyResolverRequest) -> Result<LoadBalancerResolver, Error> {
+        let response = self.patch(&format!("/load-balancer/{}/resolvers/{}", uuid, urlencoding::encode(name)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_load_balancer_resolver(&self, uuid: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/load-balancer/{}/resolvers/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(())
+    }
+
+    async fn list_load_balancer_certificate_bundles(&self) -> Result<Vec<LoadBalancerCertificateBundle>, Error> {
+        let response = self.get("/load-balancer/certificate-bundles").await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_load_balancer_certificate_bundle(&self, uuid: &str) -> Result<LoadBalancerCertificateBundle, Error> {
+        let response = self.get(&format!("/load-balancer/certificate-bundles/{}", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_load_balancer_certificate_bundle(&self, request: &CreateCertificateBundleRequest) -> Result<LoadBalancerCertificateBundle, Error> {
+        let response = self.post("/load-balancer/certificate-bundles", Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_load_balancer_certificate_bundle(&self, uuid: &str, request: &ModifyCertificateBundleRequest) -> Result<LoadBalancerCertificateBundle, Error> {
+        let response = self.patch(&format!("/load-balancer/certificate-bundles/{}", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_load_balancer_certificate_bundle(&self, uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/load-balancer/certificate-bundles/{}", uuid)).await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_create_load_balancer() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("POST", "/1.3/load-balancer")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "name": "web-lb",
+            "plan": "development",
+            "zone": "fi-hel1",
+            "configured_status": "started",
+            "networks": [{"name": "public", "type": "public", "family": "IPv4"}],
+            "frontends": [{
+                "name": "web",
+                "mode": "http",
+                "port": 80,
+                "default_backend": "web",
+                "networks": [{"name": "public"}],
+                "rules": [{
+                    "name": "api",
+                    "priority": 100,
+                    "matchers": [
+                        {"type": "path", "match_path": {"method": "starts", "value": "/api"}},
+                        {"type": "src_ip", "match_src_ip": {"value": "10.0.0.0/8"}, "inverse": true}
+                    ],
+                    "actions": [
+                        {"type": "use_backend", "action_use_backend": {"backend": "api"}},
+                        {"type": "set_forwarded_headers", "action_set_forwarded_headers": {}}
+                    ]
+                }]
+            }],
+            "backends": [{
+                "name": "web",
+                "members": [{"name": "web-1", "type": "static", "ip": "10.0.0.2", "port": 8080, "weight": 100, "max_sessions": 1000, "enabled": true, "backup": false}]
+            }],
+            "resolvers": []
+        })))
+        .with_status(201)
+        .with_body(r#"{"uuid":"0aded5c1-c7a3-498a-b9c8-a871611c47a2","name":"web-lb","zone":"fi-hel1","plan":"development","configured_status":"started","operational_state":"pending","networks":[{"name":"public","type":"public","family":"IPv4","dns_name":"lb-0aded5c1.upcloudlb.com"}],"frontends":[{"name":"web","mode":"http","port":80,"default_backend":"web","rules":[{"name":"api","priority":100,"matchers":[{"type":"path","match_path":{"method":"starts","value":"/api"}},{"type":"src_ip","inverse":true,"match_src_ip":{"value":"10.0.0.0/8"}}],"actions":[{"type":"use_backend","action_use_backend":{"backend":"api"}},{"type":"set_forwarded_headers","action_set_forwarded_headers":{}}]}],"tls_configs":[]}],"backends":[{"name":"web","members":[{"name":"web-1","type":"static","ip":"10.0.0.2","port":8080,"weight":100,"max_sessions":1000,"enabled":true,"backup":false}]}],"resolvers":[],"labels":[]}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let request = CreateLoadBalancerRequest::new("web-lb", "development", "fi-hel1")
+        .with_network(LoadBalancerNetwork::public("public"))
+        .with_frontend(
+            CreateFrontendRequest::new("web", LoadBalancerMode::Http, 80, "web")
+                .with_network("public")
+                .with_rule(
+                    CreateRuleRequest::new("api", 100)
+                        .with_matcher(RuleMatcher::path(StringMatcherMethod::Starts, "/api"))
+                        .with_matcher(RuleMatcher::src_ip("10.0.0.0/8").inverse())
+                        .with_action(RuleAction::use_backend("api"))
+                        .with_action(RuleAction::set_forwarded_headers())
+                )
+        )
+        .with_backend(
+            CreateBackendRequest::new("web")
+                .with_member(CreateBackendMemberRequest::new_static("web-1", "10.0.0.2", 8080))
+        );
+
+    let result = client.create_load_balancer(&request).await.unwrap();
+    assert_eq!(result.uuid, "0aded5c1-c7a3-498a-b9c8-a871611c47a2");
+    assert_eq!(result.configured_status, LoadBalancerConfiguredStatus::Started);
+    let rule = &result.frontends[0].rules[0];
+    assert_eq!(rule.matchers[1], RuleMatcher::src_ip("10.0.0.0/8").inverse());
+    assert_eq!(rule.actions[0], RuleAction::use_backend("api"));
+    assert_eq!(result.backends[0].members[0].member_type, LoadBalancerMemberType::Static);
+}
+
+#[tokio::test]
+async fn test_get_rule_with_unknown_matcher_and_action() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/load-balancer/0aded5c1-c7a3-498a-b9c8-a871611c47a2/frontends/web/rules/status")
+        .with_status(200)
+        .with_body(r#"{"name":"status","priority":10,"matchers":[{"type":"http_status_range","match_http_status_range":{"range_start":500,"range_end":599}},{"type":"geo_country","match_geo_country":{"value":"FI"}}],"actions":[{"type":"set_response_header","action_set_response_header":{"header":"Cache-Control","value":"no-store"}},{"type":"rate_limit","action_rate_limit":{"rate":10}}]}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let rule = client.get_load_balancer_rule("0aded5c1-c7a3-498a-b9c8-a871611c47a2", "web", "status").await.unwrap();
+    assert_eq!(rule.matchers[0], RuleMatcher::http_status_range(500, 599));
+    assert_eq!(rule.actions[0], RuleAction::set_response_header("Cache-Control", Some("no-store".to_string())));
+
+    let unknown = serde_json::json!({"type": "geo_country", "match_geo_country": {"value": "FI"}});
+    assert_eq!(rule.matchers[1].kind, RuleMatcherKind::Unknown(unknown.clone()));
+    assert_eq!(serde_json::to_value(&rule.matchers[1]).unwrap(), unknown);
+    assert_eq!(rule.actions[1], RuleAction::Unknown(serde_json::json!({"type": "rate_limit", "action_rate_limit": {"rate": 10}})));
+}
+
+#[tokio::test]
+async fn test_delete_load_balancer_backend_member() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("DELETE", "/1.3/load-balancer/0aded5c1-c7a3-498a-b9c8-a871611c47a2/backends/web%20pool/members/web%2F1")
+        .with_status(204)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    client.delete_load_balancer_backend_member("0aded5c1-c7a3-498a-b9c8-a871611c47a2", "web pool", "web/1").await.unwrap();
+}
@@ -0,0 +1,206 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::network::*,
+    types::common::LabelFilter,
+    types::server::{CreateServerInterface, NetworkInterface, ServerNetworking},
+    client::Client,
+};
+
+/// Operations for managing SDN networks and server network interfaces.
+///
+/// This trait provides methods for creating, modifying and deleting private
+/// networks, and for adding, modifying and removing the network interfaces
+/// of a server.
+#[async_trait]
+pub trait NetworkOperations {
+    async fn list_networks(&self) -> Result<NetworkList, Error>;
+    async fn list_networks_in_zone(&self, zone: &str) -> Result<NetworkList, Error>;
+    async fn list_networks_by_labels(&self, filter: &LabelFilter) -> Result<NetworkList, Error>;
+    async fn get_network(&self, uuid: &str) -> Result<Network, Error>;
+    async fn create_network(&self, request: &CreateNetworkRequest) -> Result<Network, Error>;
+    async fn modify_network(&self, uuid: &str, request: &ModifyNetworkRequest) -> Result<Network, Error>;
+    async fn delete_network(&self, uuid: &str) -> Result<(), Error>;
+    async fn get_server_networking(&self, server_uuid: &str) -> Result<ServerNetworking, Error>;
+    async fn create_server_interface(&self, server_uuid: &str, request: &CreateServerInterface) -> Result<NetworkInterface, Error>;
+    async fn modify_server_interface(&self, server_uuid: &str, index: i32, request: &NetworkInterface) -> Result<NetworkInterface, Error>;
+    async fn delete_server_interface(&self, server_uuid: &str, index: i32) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl NetworkOperations for Client {
+    async fn list_networks(&self) -> Result<NetworkList, Error> {
+        let response = self.get("/network").await?;
+        let details: GetNetworksResponse = serde_json::from_str(&response)?;
+        Ok(details.networks)
+    }
+
+    async fn list_networks_in_zone(&self, zone: &str) -> Result<NetworkList, Error> {
+        let response = self.get(&format!("/network/?zone={}", urlencoding::encode(zone))).await?;
+        let details: GetNetworksResponse = serde_json::from_str(&response)?;
+        Ok(details.networks)
+    }
+
+    async fn list_networks_by_labels(&self, filter: &LabelFilter) -> Result<NetworkList, Error> {
+        let query = filter.to_query_params();
+        let path = if query.is_empty() {
+            "/network".to_string()
+        } else {
+            format!("/network?{}", query)
+        };
+
+        let response = self.get(&path).await?;
+        let details: GetNetworksResponse = serde_json::from_str(&response)?;
+        Ok(details.networks)
+    }
+
+    async fn get_network(&self, uuid: &str) -> Result<Network, Error> {
+        let response = self.get(&format!("/network/{}", uuid)).await?;
+        let details: GetNetworkDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.network)
+    }
+
+    async fn create_network(&self, request: &CreateNetworkRequest) -> Result<Network, Error> {
+        let response = self.post("/network", Some(request)).await?;
+        let details: GetNetworkDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.network)
+    }
+
+    async fn modify_network(&self, uuid: &str, request: &ModifyNetworkRequest) -> Result<Network, Error> {
+        let response = self.put(&format!("/network/{}", uuid), Some(request)).await?;
+        let details: GetNetworkDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.network)
+    }
+
+    async fn delete_network(&self, uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/network/{}", uuid)).await?;
+        Ok(())
+    }
+
+    async fn get_server_networking(&self, server_uuid: &str) -> Result<ServerNetworking, Error> {
+        let response = self.get(&format!("/server/{}/networking", server_uuid)).await?;
+        let details: GetServerNetworkingResponse = serde_json::from_str(&response)?;
+        Ok(details.networking)
+    }
+
+    async fn create_server_interface(&self, server_uuid: &str, request: &CreateServerInterface) -> Result<NetworkInterface, Error> {
+        let body = CreateServerInterfaceRequestWrapper { interface: request.clone() };
+        let response = self.post(&format!("/server/{}/networking/interface", server_uuid), Some(&body)).await?;
+        let details: ServerInterfaceResponse = serde_json::from_str(&response)?;
+        Ok(details.interface)
+    }
+
+    async fn modify_server_interface(&self, server_uuid: &str, index: i32, request: &NetworkInterface) -> Result<NetworkInterface, Error> {
+        let body = ModifyServerInterfaceRequestWrapper { interface: request.clone() };
+        let response = self.put(&format!("/server/{}/networking/interface/{}", server_uuid, index), Some(&body)).await?;
+        let details: ServerInterfaceResponse = serde_json::from_str(&response)?;
+        Ok(details.interface)
+    }
+
+    async fn delete_server_interface(&self, server_uuid: &str, index: i32) -> Result<(), Error> {
+        self.delete(&format!("/server/{}/networking/interface/{}", server_uuid, index)).await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_create_network() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("POST", "/1.3/network")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "network": {
+                "name": "backend",
+                "zone": "fi-hel1",
+                "ip_networks": {"ip_network": [{
+                    "address": "172.16.0.0/22",
+                    "family": "IPv4",
+                    "dhcp": "yes",
+                    "dhcp_default_route": "no",
+                    "dhcp_dns": {"dhcp_dns": ["172.16.0.10"]},
+                    "gateway": "172.16.0.1"
+                }]}
+            }
+        })))
+        .with_status(201)
+        .with_body(r#"{"network":{"name":"backend","type":"private","uuid":"03e4970d-7791-4b80-a892-682ae0faf46b","zone":"fi-hel1","router":"","ip_networks":{"ip_network":[{"address":"172.16.0.0/22","dhcp":"yes","dhcp_default_route":"no","dhcp_dns":{"dhcp_dns":["172.16.0.10"]},"family":"IPv4","gateway":"172.16.0.1"}]},"servers":{"server":[]}}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let request = CreateNetworkRequest::new("backend", "fi-hel1")
+        .with_ip_network(
+            IpNetwork::new("172.16.0.0/22", "IPv4")
+                .with_dhcp(true)
+                .with_dhcp_default_route(false)
+                .with_dhcp_dns("172.16.0.10")
+                .with_gateway("172.16.0.1")
+        );
+    let result = client.create_network(&request).await.unwrap();
+    assert_eq!(result.uuid, "03e4970d-7791-4b80-a892-682ae0faf46b");
+    assert_eq!(result.router, None);
+    assert_eq!(result.ip_networks.ip_network[0].dhcp, Some(true));
+}
+
+#[tokio::test]
+async fn test_create_server_interface() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("POST", "/1.3/server/00798b85-efdc-41ca-8021-f6ef457b8531/networking/interface")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "interface": {
+                "type": "private",
+                "network": "03e4970d-7791-4b80-a892-682ae0faf46b",
+                "ip_addresses": {"ip_address": [{"family": "IPv4", "address": null}]},
+                "index": 3
+            }
+        })))
+        .with_status(202)
+        .with_body(r#"{"interface":{"index":3,"ip_addresses":{"ip_address":[{"address":"172.16.0.2","family":"IPv4","floating":"no"}]},"mac":"de:ff:ff:ff:ed:85","network":"03e4970d-7791-4b80-a892-682ae0faf46b","source_ip_filtering":"yes","type":"private","bootable":"no"}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let interface = CreateServerInterface::new(NETWORK_TYPE_PRIVATE)
+        .with_network("03e4970d-7791-4b80-a892-682ae0faf46b")
+        .with_ip_address("IPv4", None)
+        .with_index(3);
+
+    let result = client.create_server_interface("00798b85-efdc-41ca-8021-f6ef457b8531", &interface).await.unwrap();
+    assert_eq!(result.index, Some(3));
+    assert_eq!(result.source_ip_filtering, Some(true));
+    assert_eq!(result.ip_addresses.unwrap()[0].address, "172.16.0.2");
+}
+
+#[tokio::test]
+async fn test_get_server_networking() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/server/00798b85-efdc-41ca-8021-f6ef457b8531/networking")
+        .with_status(200)
+        .with_body(r#"{"networking":{"interfaces":{"interface":[{"index":1,"ip_addresses":{"ip_address":[{"address":"94.237.0.207","family":"IPv4","floating":"no"}]},"mac":"de:ff:ff:ff:66:89","network":"037fcf2a-6745-45dd-867e-f9479ea8c044","type":"public","source_ip_filtering":"yes","bootable":"no"}]}}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let result = client.get_server_networking("00798b85-efdc-41ca-8021-f6ef457b8531").await.unwrap();
+    assert_eq!(result.interfaces.len(), 1);
+    assert_eq!(result.interfaces[0].mac.as_deref(), Some("de:ff:ff:ff:66:89"));
+    assert_eq!(result.interfaces[0].ip_addresses.as_ref().unwrap()[0].address, "94.237.0.207");
+}
@@ -0,0 +1,266 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::object_storage::*,
+    types::common::LabelFilter,
+    client::Client,
+};
+
+/// Operations for managing Managed Object Storage services.
+///
+/// This trait covers the management API only: services, regions, users,
+/// access keys, policies and buckets. Object data itself is accessed through
+/// the S3-compatible endpoints of the service.
+#[async_trait]
+pub trait ObjectStorageOperations {
+    async fn list_object_storages(&self) -> Result<Vec<ObjectStorage>, Error>;
+    async fn list_object_storages_by_labels(&self, filter: &LabelFilter) -> Result<Vec<ObjectStorage>, Error>;
+    async fn get_object_storage(&self, uuid: &str) -> Result<ObjectStorage, Error>;
+    async fn create_object_storage(&self, request: &CreateObjectStorageRequest) -> Result<ObjectStorage, Error>;
+    async fn modify_object_storage(&self, uuid: &str, request: &ModifyObjectStorageRequest) -> Result<ObjectStorage, Error>;
+    async fn delete_object_storage(&self, uuid: &str) -> Result<(), Error>;
+    async fn list_object_storage_regions(&self) -> Result<Vec<ObjectStorageRegion>, Error>;
+    async fn get_object_storage_region(&self, name: &str) -> Result<ObjectStorageRegion, Error>;
+    async fn list_object_storage_users(&self, uuid: &str) -> Result<Vec<ObjectStorageUser>, Error>;
+    async fn get_object_storage_user(&self, uuid: &str, username: &str) -> Result<ObjectStorageUser, Error>;
+    async fn create_object_storage_user(&self, uuid: &str, request: &CreateObjectStorageUserRequest) -> Result<ObjectStorageUser, Error>;
+    async fn delete_object_storage_user(&self, uuid: &str, username: &str) -> Result<(), Error>;
+    async fn list_object_storage_access_keys(&self, uuid: &str, username: &str) -> Result<Vec<ObjectStorageAccessKey>, Error>;
+    async fn create_object_storage_access_key(&self, uuid: &str, username: &str) -> Result<ObjectStorageAccessKey, Error>;
+    async fn modify_object_storage_access_key(&self, uuid: &str, username: &str, access_key_id: &str, request: &ModifyObjectStorageAccessKeyRequest) -> Result<ObjectStorageAccessKey, Error>;
+    async fn delete_object_storage_access_key(&self, uuid: &str, username: &str, access_key_id: &str) -> Result<(), Error>;
+    async fn list_object_storage_policies(&self, uuid: &str) -> Result<Vec<ObjectStoragePolicy>, Error>;
+    async fn get_object_storage_policy(&self, uuid: &str, name: &str) -> Result<ObjectStoragePolicy, Error>;
+    async fn create_object_storage_policy(&self, uuid: &str, request: &CreateObjectStoragePolicyRequest) -> Result<ObjectStoragePolicy, Error>;
+    async fn delete_object_storage_policy(&self, uuid: &str, name: &str) -> Result<(), Error>;
+    async fn list_object_storage_user_policies(&self, uuid: &str, username: &str) -> Result<Vec<ObjectStoragePolicyRef>, Error>;
+    async fn attach_object_storage_user_policy(&self, uuid: &str, username: &str, policy: &str) -> Result<(), Error>;
+    async fn detach_object_storage_user_policy(&self, uuid: &str, username: &str, policy: &str) -> Result<(), Error>;
+    async fn list_object_storage_buckets(&self, uuid: &str) -> Result<Vec<ObjectStorageBucket>, Error>;
+    async fn create_object_storage_bucket(&self, uuid: &str, request: &CreateObjectStorageBucketRequest) -> Result<ObjectStorageBucket, Error>;
+    async fn delete_object_storage_bucket(&self, uuid: &str, name: &str) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl ObjectStorageOperations for Client {
+    async fn list_object_storages(&self) -> Result<Vec<ObjectStorage>, Error> {
+        let response = self.get("/object-storage-2").await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn list_object_storages_by_labels(&self, filter: &LabelFilter) -> Result<Vec<ObjectStorage>, Error> {
+        let query = filter.to_query_params();
+        let path = if query.is_empty() {
+            "/object-storage-2".to_string()
+        } else {
+            format!("/object-storage-2?{}", query)
+        };
+
+        let response = self.get(&path).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_object_storage(&self, uuid: &str) -> Result<ObjectStorage, Error> {
+        let response = self.get(&format!("/object-storage-2/{}", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_object_storage(&self, request: &CreateObjectStorageRequest) -> Result<ObjectStorage, Error> {
+        if let Some(labels) = &request.labels {
+            labels.validate()?;
+        }
+        let response = self.post("/object-storage-2", Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_object_storage(&self, uuid: &str, request: &ModifyObjectStorageRequest) -> Result<ObjectStorage, Error> {
+        if let Some(labels) = &request.labels {
+            labels.validate()?;
+        }
+        let response = self.patch(&format!("/object-storage-2/{}", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_object_storage(&self, uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/object-storage-2/{}", uuid)).await?;
+        Ok(())
+    }
+
+    async fn list_object_storage_regions(&self) -> Result<Vec<ObjectStorageRegion>, Error> {
+        let response = self.get("/object-storage-2/regions").await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_object_storage_region(&self, name: &str) -> Result<ObjectStorageRegion, Error> {
+        let response = self.get(&format!("/object-storage-2/regions/{}", name)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn list_object_storage_users(&self, uuid: &str) -> Result<Vec<ObjectStorageUser>, Error> {
+        let response = self.get(&format!("/object-storage-2/{}/users", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_object_storage_user(&self, uuid: &str, username: &str) -> Result<ObjectStorageUser, Error> {
+        let response = self.get(&format!("/object-storage-2/{}/users/{}", uuid, urlencoding::encode(username))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_object_storage_user(&self, uuid: &str, request: &CreateObjectStorageUserRequest) -> Result<ObjectStorageUser, Error> {
+        let response = self.post(&format!("/object-storage-2/{}/users", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_object_storage_user(&self, uuid: &str, username: &str) -> Result<(), Error> {
+        self.delete(&format!("/object-storage-2/{}/users/{}", uuid, urlencoding::encode(username))).await?;
+        Ok(())
+    }
+
+    async fn list_object_storage_access_keys(&self, uuid: &str, username: &str) -> Result<Vec<ObjectStorageAccessKey>, Error> {
+        let response = self.get(&format!("/object-storage-2/{}/users/{}/access-keys", uuid, urlencoding::encode(username))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_object_storage_access_key(&self, uuid: &str, username: &str) -> Result<ObjectStorageAccessKey, Error> {
+        let response = self.post(&format!("/object-storage-2/{}/users/{}/access-keys", uuid, urlencoding::encode(username)), Option::<&()>::None).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn modify_object_storage_access_key(&self, uuid: &str, username: &str, access_key_id: &str, request: &ModifyObjectStorageAccessKeyRequest) -> Result<ObjectStorageAccessKey, Error> {
+        let response = self.patch(&format!("/object-storage-2/{}/users/{}/access-keys/{}", uuid, urlencoding::encode(username), urlencoding::encode(access_key_id)), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_object_storage_access_key(&self, uuid: &str, username: &str, access_key_id: &str) -> Result<(), Error> {
+        self.delete(&format!("/object-storage-2/{}/users/{}/access-keys/{}", uuid, urlencoding::encode(username), urlencoding::encode(access_key_id))).await?;
+        Ok(())
+    }
+
+    async fn list_object_storage_policies(&self, uuid: &str) -> Result<Vec<ObjectStoragePolicy>, Error> {
+        let response = self.get(&format!("/object-storage-2/{}/policies", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn get_object_storage_policy(&self, uuid: &str, name: &str) -> Result<ObjectStoragePolicy, Error> {
+        let response = self.get(&format!("/object-storage-2/{}/policies/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_object_storage_policy(&self, uuid: &str, request: &CreateObjectStoragePolicyRequest) -> Result<ObjectStoragePolicy, Error> {
+        let response = self.post(&format!("/object-storage-2/{}/policies", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_object_storage_policy(&self, uuid: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/object-storage-2/{}/policies/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(())
+    }
+
+    async fn list_object_storage_user_policies(&self, uuid: &str, username: &str) -> Result<Vec<ObjectStoragePolicyRef>, Error> {
+        let response = self.get(&format!("/object-storage-2/{}/users/{}/policies", uuid, urlencoding::encode(username))).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn attach_object_storage_user_policy(&self, uuid: &str, username: &str, policy: &str) -> Result<(), Error> {
+        let request = ObjectStoragePolicyRef::new(policy);
+        self.post(&format!("/object-storage-2/{}/users/{}/policies", uuid, urlencoding::encode(username)), Some(&request)).await?;
+        Ok(())
+    }
+
+    async fn detach_object_storage_user_policy(&self, uuid: &str, username: &str, policy: &str) -> Result<(), Error> {
+        self.delete(&format!("/object-storage-2/{}/users/{}/policies/{}", uuid, urlencoding::encode(username), urlencoding::encode(policy))).await?;
+        Ok(())
+    }
+
+    async fn list_object_storage_buckets(&self, uuid: &str) -> Result<Vec<ObjectStorageBucket>, Error> {
+        let response = self.get(&format!("/object-storage-2/{}/buckets", uuid)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_object_storage_bucket(&self, uuid: &str, request: &CreateObjectStorageBucketRequest) -> Result<ObjectStorageBucket, Error> {
+        let response = self.post(&format!("/object-storage-2/{}/buckets", uuid), Some(request)).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn delete_object_storage_bucket(&self, uuid: &str, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/object-storage-2/{}/buckets/{}", uuid, urlencoding::encode(name))).await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_list_object_storages_by_labels() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/object-storage-2?label=team%3Ddata")
+        .with_status(200)
+        .with_body(r#"[{"uuid":"1200ecde-db95-4d1c-9133-6508f3232567","name":"example","region":"europe-1","configured_status":"started","operational_state":"running","endpoints":[{"domain_name":"7mf5k.upbucket.com","type":"public","iam_url":"https://7mf5k.upbucket.com:4443/iam","sts_url":"https://7mf5k.upbucket.com:4443/sts"}],"networks":[{"name":"example-public-network","type":"public","family":"IPv4"}],"labels":[{"key":"team","value":"data"}],"users":[]}]"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let result = client.list_object_storages_by_labels(&LabelFilter::new().with("team", "data")).await.unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].configured_status, ObjectStorageConfiguredStatus::Started);
+    assert_eq!(result[0].labels.as_ref().unwrap().label[0].value, "data");
+    assert_eq!(result[0].endpoints[0].endpoint_type, "public");
+}
+
+#[tokio::test]
+async fn test_create_object_storage_with_labels() {
+    use crate::config;
+    use crate::types::common::Labels;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("POST", "/1.3/object-storage-2")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "name": "example",
+            "region": "europe-1",
+            "networks": [{"name": "example-public-network", "type": "public", "family": "IPv4"}],
+            "labels": [{"key": "team", "value": "data"}]
+        })))
+        .with_status(201)
+        .with_body(r#"{"uuid":"1200ecde-db95-4d1c-9133-6508f3232567","name":"example","region":"europe-1","configured_status":"started","operational_state":"pending","labels":[{"key":"team","value":"data"}]}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let request = CreateObjectStorageRequest::new("example", "europe-1")
+        .with_network(ObjectStorageNetwork::public("example-public-network"))
+        .with_labels(Labels::new().with("team", "data"));
+    let result = client.create_object_storage(&request).await.unwrap();
+    assert_eq!(result.operational_state, "pending");
+
+    let request = CreateObjectStorageRequest::new("example", "europe-1")
+        .with_labels(Labels::new().with("_team", "data"));
+    let err = client.create_object_storage(&request).await.unwrap_err();
+    assert!(matches!(err, Error::ValidationError(_)));
+}
+
+#[tokio::test]
+async fn test_detach_object_storage_user_policy() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("DELETE", "/1.3/object-storage-2/1200ecde-db95-4d1c-9133-6508f3232567/users/ci%2Fdeploy/policies/Read%20Only")
+        .with_status(204)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    client.detach_object_storage_user_policy("1200ecde-db95-4d1c-9133-6508f3232567", "ci/deploy", "Read Only").await.unwrap();
+}
@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::router::*,
+    types::network::{Network, GetNetworkDetailsResponse},
+    types::common::LabelFilter,
+    client::Client,
+};
+
+/// Operations for managing SDN routers.
+///
+/// Routers connect private networks to each other. A network can have at
+/// most one router attached.
+#[async_trait]
+pub trait RouterOperations {
+    async fn list_routers(&self) -> Result<RouterList, Error>;
+    async fn list_routers_by_labels(&self, filter: &LabelFilter) -> Result<RouterList, Error>;
+    async fn get_router(&self, uuid: &str) -> Result<Router, Error>;
+    async fn create_router(&self, request: &CreateRouterRequest) -> Result<Router, Error>;
+    async fn modify_router(&self, uuid: &str, request: &ModifyRouterRequest) -> Result<Router, Error>;
+    async fn delete_router(&self, uuid: &str) -> Result<(), Error>;
+    async fn attach_network_router(&self, network_uuid: &str, router_uuid: &str) -> Result<Network, Error>;
+    async fn detach_network_router(&self, network_uuid: &str) -> Result<Network, Error>;
+}
+
+#[async_trait]
+impl RouterOperations for Client {
+    async fn list_routers(&self) -> Result<RouterList, Error> {
+        let response = self.get("/router").await?;
+        let details: GetRoutersResponse = serde_json::from_str(&response)?;
+        Ok(details.routers)
+    }
+
+    async fn list_routers_by_labels(&self, filter: &LabelFilter) -> Result<RouterList, Error> {
+        let query = filter.to_query_params();
+        let path = if query.is_empty() {
+            "/router".to_string()
+        } else {
+            format!("/router?{}", query)
+        };
+
+        let response = self.get(&path).await?;
+        let details: GetRoutersResponse = serde_json::from_str(&response)?;
+        Ok(details.routers)
+    }
+
+    async fn get_router(&self, uuid: &str) -> Result<Router, Error> {
+        let response = self.get(&format!("/router/{}", uuid)).await?;
+        let details: GetRouterDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.router)
+    }
+
+    async fn create_router(&self, request: &CreateRouterRequest) -> Result<Router, Error> {
+        let response = self.post("/router", Some(request)).await?;
+        let details: GetRouterDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.router)
+    }
+
+    async fn modify_router(&self, uuid: &str, request: &ModifyRouterRequest) -> Result<Router, Error> {
+        let response = self.patch(&format!("/router/{}", uuid), Some(request)).await?;
+        let details: GetRouterDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.router)
+    }
+
+    async fn delete_router(&self, uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/router/{}", uuid)).await?;
+        Ok(())
+    }
+
+    async fn attach_network_router(&self, network_uuid: &str, router_uuid: &str) -> Result<Network, Error> {
+        let request = NetworkRouterRequest::attach(router_uuid);
+        let response = self.put(&format!("/network/{}", network_uuid), Some(&request)).await?;
+        let details: GetNetworkDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.network)
+    }
+
+    async fn detach_network_router(&self, network_uuid: &str) -> Result<Network, Error> {
+        let request = NetworkRouterRequest::detach();
+        let response = self.put(&format!("/network/{}", network_uuid), Some(&request)).await?;
+        let details: GetNetworkDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.network)
+    }
+}
+
+#[tokio::test]
+async fn test_list_routers_by_labels() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/router?label=env%3Dprod")
+        .with_status(200)
+        .with_body(r#"{"routers":{"router":[{"attached_networks":{"network":[{"uuid":"03e4970d-7791-4b80-a892-682ae0faf46b"}]},"labels":{"label":[{"key":"env","value":"prod"}]},"name":"backbone","static_routes":[{"name":"to-dc","nexthop":"172.16.0.10","route":"10.0.0.0/8","type":"user"}],"type":"normal","uuid":"04c0df35-2658-4b0c-8ad7-0d0b4b9a8d23"}]}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let result = client.list_routers_by_labels(&LabelFilter::new().with("env", "prod")).await.unwrap();
+    assert_eq!(result.router.len(), 1);
+    assert_eq!(result.router[0].attached_networks.network[0].uuid, "03e4970d-7791-4b80-a892-682ae0faf46b");
+    assert_eq!(result.router[0].static_routes[0].route, "10.0.0.0/8");
+    assert_eq!(result.router[0].static_routes[0].route_type, STATIC_ROUTE_TYPE_USER);
+}
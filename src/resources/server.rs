@@ -3,10 +3,13 @@ use async_trait::async_trait;
 use crate::{
     error::Error,
     types::server::*,
-    types::common::LabelFilter,
+    types::common::{LabelFilter, Page},
     client::Client,
+    pagination::paginate,
 };
 
+use futures::stream::{BoxStream, StreamExt};
+
 use tokio::time::{sleep, Duration};
 
 /// Operations for managing cloud servers.
@@ -16,6 +19,9 @@ use tokio::time::{sleep, Duration};
 #[async_trait]
 pub trait ServerOperations {
     async fn list_servers(&self) -> Result<ServerList, Error>;
+    async fn list_servers_page(&self, page: &Page) -> Result<ServerList, Error>;
+    /// Streams all servers, fetching `page_size` servers per request.
+    fn stream_servers(&self, page_size: u32) -> BoxStream<'_, Result<Server, Error>>;
     async fn get_server(&self, uuid: &str) -> Result<ServerDetails, Error>;
     async fn create_server(&self, request: &CreateServerRequest) -> Result<ServerDetails, Error>;
     async fn start_server(&self, uuid: &str, request: &StartServerRequest) -> Result<StartServerResponse, Error>;
@@ -25,6 +31,11 @@ pub trait ServerOperations {
     async fn delete_server(&self, uuid: &str) -> Result<(), Error>;
     async fn delete_server_and_storages(&self, uuid: &str, delete_backups: bool) -> Result<(), Error>;
     async fn list_servers_by_labels(&self, filter: &LabelFilter) -> Result<ServerList, Error>;
+    /// Lists servers that have all of the given tags. Tag names are
+    /// compared case-insensitively.
+    async fn list_servers_by_tags(&self, tags: &[&str]) -> Result<ServerList, Error>;
+    async fn load_cdrom(&self, uuid: &str, request: &LoadCdromRequest) -> Result<ServerDetails, Error>;
+    async fn eject_cdrom(&self, uuid: &str) -> Result<ServerDetails, Error>;
     async fn wait_for_server_state(
         &self,
         uuid: &str,
@@ -42,6 +53,19 @@ impl ServerOperations for Client {
         Ok(details.servers)
     }
 
+    async fn list_servers_page(&self, page: &Page) -> Result<ServerList, Error> {
+        let response = self.get(&format!("/server?{}", page.to_query_params())).await?;
+        let details: GetServerResponse = serde_json::from_str(&response)?;
+        Ok(details.servers)
+    }
+
+    fn stream_servers(&self, page_size: u32) -> BoxStream<'_, Result<Server, Error>> {
+        paginate(page_size, move |page| async move {
+            Ok(self.list_servers_page(&page).await?.server)
+        })
+        .boxed()
+    }
+
     async fn list_servers_by_labels(&self, filter: &LabelFilter) -> Result<ServerList, Error> {
         let query = filter.to_query_params();
         let path = if query.is_empty() {
@@ -55,6 +79,14 @@ impl ServerOperations for Client {
         Ok(details.servers)
     }
 
+    async fn list_servers_by_tags(&self, tags: &[&str]) -> Result<ServerList, Error> {
+        let mut servers = self.list_servers().await?;
+        servers.server.retain(|server| {
+            tags.iter().all(|tag| server.tags.tag.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        });
+        Ok(servers)
+    }
+
     async fn get_server(&self, uuid: &str) -> Result<ServerDetails, Error> {
         let response = self.get(&format!("/server/{}", uuid)).await?;
         let details: GetServerDetailsResponse = serde_json::from_str(&response)?;
@@ -83,7 +115,8 @@ impl ServerOperations for Client {
     }
 
     async fn modify_server(&self, uuid: &str, request: &ModifyServerRequest) -> Result<ModifyServerResponse, Error> {
-        let response = self.put(&format!("/server/{}", uuid), Some(request)).await?;
+        let body = ModifyServerRequestWrapper { server: request.clone() };
+        let response = self.put(&format!("/server/{}", uuid), Some(&body)).await?;
         Ok(serde_json::from_str(&response)?)
     }
 
@@ -102,6 +135,18 @@ impl ServerOperations for Client {
         Ok(())
     }
 
+    async fn load_cdrom(&self, uuid: &str, request: &LoadCdromRequest) -> Result<ServerDetails, Error> {
+        let response = self.post(&format!("/server/{}/cdrom/load", uuid), Some(request)).await?;
+        let details: GetServerDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.server)
+    }
+
+    async fn eject_cdrom(&self, uuid: &str) -> Result<ServerDetails, Error> {
+        let response = self.post(&format!("/server/{}/cdrom/eject", uuid), Option::<&()>::None).await?;
+        let details: GetServerDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.server)
+    }
+
     async fn wait_for_server_state(
         &self,
         uuid: &str,
@@ -153,10 +198,141 @@ async fn test_list_servers() {
     assert_eq!(result.server[0].title, "Helsinki server");
 }
 
+#[tokio::test]
+async fn test_rescue_boot_from_cdrom() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let details = r#"{"server":{"boot_order":"cdrom,disk","core_number":"1","hostname":"fi.example.com","license":0,"memory_amount":"2048","plan":"1xCPU-2GB","remote_access_enabled":"no","remote_access_password":"","state":"stopped","title":"Helsinki server","uuid":"00798b85-efdc-41ca-8021-f6ef457b8531","zone":"fi-hel1"}}"#;
+    let _load = server.mock("POST", "/1.3/server/00798b85-efdc-41ca-8021-f6ef457b8531/cdrom/load")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"storage_device":{"storage":"01000000-0000-4000-8000-000070010101"}})))
+        .with_status(200)
+        .with_body(details)
+        .create();
+    let _modify = server.mock("PUT", "/1.3/server/00798b85-efdc-41ca-8021-f6ef457b8531")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"server":{"boot_order":"cdrom,disk"}})))
+        .with_status(202)
+        .with_body(details)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let uuid = "00798b85-efdc-41ca-8021-f6ef457b8531";
+    client.load_cdrom(uuid, &LoadCdromRequest::new("01000000-0000-4000-8000-000070010101")).await.unwrap();
+
+    let boot_order = BootOrder::new().with(BootDevice::Cdrom).with(BootDevice::Disk);
+    let result = client.modify_server(uuid, &ModifyServerRequest::new().with_boot_order(boot_order)).await.unwrap();
+    assert_eq!(result.server.boot_order, Some(BootOrder::new().with(BootDevice::Cdrom).with(BootDevice::Disk)));
+}
+
+#[tokio::test]
+async fn test_get_server_with_unknown_boot_device() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/server/00798b85-efdc-41ca-8021-f6ef457b8531")
+        .with_status(200)
+        .with_body(r#"{"server":{"boot_order":"disk,uefi_http","core_number":"1","hostname":"fi.example.com","license":0,"memory_amount":"2048","plan":"1xCPU-2GB","remote_access_enabled":"no","remote_access_password":"","state":"started","title":"Helsinki server","uuid":"00798b85-efdc-41ca-8021-f6ef457b8531","zone":"fi-hel1"}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let details = client.get_server("00798b85-efdc-41ca-8021-f6ef457b8531").await.unwrap();
+    let boot_order = details.boot_order.unwrap();
+    assert_eq!(boot_order.devices, vec![BootDevice::Disk, BootDevice::Unknown("uefi_http".to_string())]);
+    assert_eq!(boot_order.to_string(), "disk,uefi_http");
+}
+
+#[tokio::test]
+async fn test_get_server_not_found() {
+    use crate::config;
+    use crate::error::ErrorCode;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/server/00798b85-efdc-41ca-8021-f6ef457b8531")
+        .with_status(404)
+        .with_body(r#"{"error":{"error_code":"SERVER_NOT_FOUND","error_message":"The server 00798b85-efdc-41ca-8021-f6ef457b8531 does not exist."}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let err = client.get_server("00798b85-efdc-41ca-8021-f6ef457b8531").await.unwrap_err();
+    assert!(err.is_not_found());
+    assert!(!err.is_rate_limited());
+    assert_eq!(err.error_code(), Some(&ErrorCode::ServerNotFound));
+}
+
 // TODO: Add test for get_server
 #[tokio::test]
 async fn test_get_server() {}
 
 // TODO: Add test for create_server
 #[tokio::test]
-async fn test_create_server() {}
\ No newline at end of file
+async fn test_create_server() {}
+
+#[tokio::test]
+async fn test_stream_servers() {
+    use crate::config;
+    use futures::{StreamExt, TryStreamExt};
+
+    let server_json = |uuid: &str, state: &str| format!(
+        r#"{{"core_number":"1","hostname":"{uuid}.example.com","license":0,"memory_amount":"1024","plan":"1xCPU-1GB","state":"{state}","tags":{{"tag":[]}},"title":"{uuid}","uuid":"{uuid}","zone":"fi-hel1"}}"#
+    );
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let first = server.mock("GET", "/1.3/server?limit=2&offset=0")
+        .with_status(200)
+        .with_body(format!(r#"{{"servers":{{"server":[{},{}]}}}}"#, server_json("a", "started"), server_json("b", "stopped")))
+        .expect(1)
+        .create();
+    let second = server.mock("GET", "/1.3/server?limit=2&offset=2")
+        .with_status(200)
+        .with_body(format!(r#"{{"servers":{{"server":[{}]}}}}"#, server_json("c", "started")))
+        .expect(1)
+        .create();
+    let third = server.mock("GET", "/1.3/server?limit=2&offset=3")
+        .with_status(200)
+        .with_body(r#"{"servers":{"server":[]}}"#)
+        .expect(1)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let started: Vec<Server> = client.stream_servers(2)
+        .try_filter(|s| futures::future::ready(s.state == "started"))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(started.iter().map(|s| s.uuid.as_str()).collect::<Vec<_>>(), vec!["a", "c"]);
+    first.assert();
+    second.assert();
+    third.assert();
+
+    // Taking fewer items than a page holds fetches only the first page.
+    first.remove();
+    let first_again = server.mock("GET", "/1.3/server?limit=2&offset=0")
+        .with_status(200)
+        .with_body(format!(r#"{{"servers":{{"server":[{},{}]}}}}"#, server_json("a", "started"), server_json("b", "stopped")))
+        .expect(1)
+        .create();
+    let first_only: Vec<_> = client.stream_servers(2).take(1).collect().await;
+    assert_eq!(first_only.len(), 1);
+    first_again.assert();
+    second.assert();
+}
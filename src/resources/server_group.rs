@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::server_group::*,
+    client::Client,
+};
+
+/// Operations for managing server groups.
+///
+/// Server groups with an anti-affinity policy keep their member servers on
+/// separate hosts.
+#[async_trait]
+pub trait ServerGroupOperations {
+    async fn list_server_groups(&self) -> Result<ServerGroupList, Error>;
+    async fn get_server_group(&self, uuid: &str) -> Result<ServerGroup, Error>;
+    async fn create_server_group(&self, request: &CreateServerGroupRequest) -> Result<ServerGroup, Error>;
+    async fn modify_server_group(&self, uuid: &str, request: &ModifyServerGroupRequest) -> Result<ServerGroup, Error>;
+    async fn delete_server_group(&self, uuid: &str) -> Result<(), Error>;
+    async fn add_server_to_server_group(&self, uuid: &str, server_uuid: &str) -> Result<(), Error>;
+    async fn remove_server_from_server_group(&self, uuid: &str, server_uuid: &str) -> Result<(), Error>;
+    async fn get_server_group_anti_affinity_status(&self, uuid: &str) -> Result<Vec<AntiAffinityStatus>, Error>;
+}
+
+#[async_trait]
+impl ServerGroupOperations for Client {
+    async fn list_server_groups(&self) -> Result<ServerGroupList, Error> {
+        let response = self.get("/server-group").await?;
+        let details: GetServerGroupsResponse = serde_json::from_str(&response)?;
+        Ok(details.server_groups)
+    }
+
+    async fn get_server_group(&self, uuid: &str) -> Result<ServerGroup, Error> {
+        let response = self.get(&format!("/server-group/{}", uuid)).await?;
+        let details: GetServerGroupResponse = serde_json::from_str(&response)?;
+        Ok(details.server_group)
+    }
+
+    async fn create_server_group(&self, request: &CreateServerGroupRequest) -> Result<ServerGroup, Error> {
+        let response = self.post("/server-group", Some(request)).await?;
+        let details: GetServerGroupResponse = serde_json::from_str(&response)?;
+        Ok(details.server_group)
+    }
+
+    async fn modify_server_group(&self, uuid: &str, request: &ModifyServerGroupRequest) -> Result<ServerGroup, Error> {
+        let response = self.patch(&format!("/server-group/{}", uuid), Some(request)).await?;
+        let details: GetServerGroupResponse = serde_json::from_str(&response)?;
+        Ok(details.server_group)
+    }
+
+    async fn delete_server_group(&self, uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/server-group/{}", uuid)).await?;
+        Ok(())
+    }
+
+    async fn add_server_to_server_group(&self, uuid: &str, server_uuid: &str) -> Result<(), Error> {
+        let request = AddServerGroupMemberRequest::new(server_uuid);
+        self.post(&format!("/server-group/{}/servers", uuid), Some(&request)).await?;
+        Ok(())
+    }
+
+    async fn remove_server_from_server_group(&self, uuid: &str, server_uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/server-group/{}/servers/{}", uuid, server_uuid)).await?;
+        Ok(())
+    }
+
+    async fn get_server_group_anti_affinity_status(&self, uuid: &str) -> Result<Vec<AntiAffinityStatus>, Error> {
+        let group = self.get_server_group(uuid).await?;
+        Ok(group.anti_affinity_status)
+    }
+}
+
+#[tokio::test]
+async fn test_get_server_group_anti_affinity_unmet() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/server-group/0b5c6a8b-8cc8-4e3a-9ab4-9b7a5d3b4e1f")
+        .with_status(200)
+        .with_body(r#"{"server_group":{"anti_affinity":"yes","anti_affinity_status":[{"uuid":"0044a5a1-dc8c-4ea3-b1c2-7c5c5b5a3f1e","status":"met"},{"uuid":"00c9e1b5-4a8c-4b5e-9f7e-1a7b5c3d2e1f","status":"unmet"},{"uuid":"00f1c2d3-5b6a-4c7d-8e9f-0a1b2c3d4e5f","status":"pending"}],"labels":{"label":[]},"servers":{"server":["0044a5a1-dc8c-4ea3-b1c2-7c5c5b5a3f1e","00c9e1b5-4a8c-4b5e-9f7e-1a7b5c3d2e1f"]},"title":"db-pair","uuid":"0b5c6a8b-8cc8-4e3a-9ab4-9b7a5d3b4e1f"}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let group = client.get_server_group("0b5c6a8b-8cc8-4e3a-9ab4-9b7a5d3b4e1f").await.unwrap();
+    assert_eq!(group.anti_affinity, AntiAffinityPolicy::Yes);
+    assert_eq!(group.servers.server.len(), 2);
+    assert!(!group.anti_affinity_met());
+    assert_eq!(group.unmet_servers(), vec!["00c9e1b5-4a8c-4b5e-9f7e-1a7b5c3d2e1f"]);
+    assert_eq!(group.anti_affinity_status[2].status, AntiAffinityState::Unknown);
+}
+
+#[tokio::test]
+async fn test_create_server_group() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("POST", "/1.3/server-group")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "server_group": {
+                "title": "db-pair",
+                "anti_affinity": "strict",
+                "servers": {"server": ["0044a5a1-dc8c-4ea3-b1c2-7c5c5b5a3f1e"]}
+            }
+        })))
+        .with_status(201)
+        .with_body(r#"{"server_group":{"anti_affinity":"strict","anti_affinity_status":[{"uuid":"0044a5a1-dc8c-4ea3-b1c2-7c5c5b5a3f1e","status":"met"}],"servers":{"server":["0044a5a1-dc8c-4ea3-b1c2-7c5c5b5a3f1e"]},"title":"db-pair","uuid":"0b5c6a8b-8cc8-4e3a-9ab4-9b7a5d3b4e1f"}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let request = CreateServerGroupRequest::new("db-pair")
+        .with_anti_affinity(AntiAffinityPolicy::Strict)
+        .with_server("0044a5a1-dc8c-4ea3-b1c2-7c5c5b5a3f1e");
+    let group = client.create_server_group(&request).await.unwrap();
+    assert!(group.anti_affinity_met());
+}
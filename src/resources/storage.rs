@@ -0,0 +1,352 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::storage::*,
+    types::common::{LabelFilter, Page},
+    types::server::{ServerDetails, GetServerDetailsResponse},
+    client::Client,
+    pagination::paginate,
+};
+
+use futures::stream::{BoxStream, StreamExt};
+
+use tokio::time::{sleep, Duration};
+
+/// Operations for managing storage devices.
+///
+/// This trait provides methods for listing, creating, modifying and deleting
+/// storages, attaching them to and detaching them from servers, cloning and
+/// templatizing them, importing disk images into them, and taking and
+/// restoring backups.
+#[async_trait]
+pub trait StorageOperations {
+    async fn list_storages(&self) -> Result<StorageList, Error>;
+    async fn list_storages_page(&self, page: &Page) -> Result<StorageList, Error>;
+    /// Streams all storages, fetching `page_size` storages per request.
+    fn stream_storages(&self, page_size: u32) -> BoxStream<'_, Result<Storage, Error>>;
+    async fn list_storages_by_type(&self, storage_type: &StorageType) -> Result<StorageList, Error>;
+    async fn list_storages_by_access(&self, access: &StorageAccess) -> Result<StorageList, Error>;
+    async fn list_storages_by_labels(&self, filter: &LabelFilter) -> Result<StorageList, Error>;
+    async fn get_storage(&self, uuid: &str) -> Result<StorageDetails, Error>;
+    async fn create_storage(&self, request: &CreateStorageRequest) -> Result<StorageDetails, Error>;
+    async fn modify_storage(&self, uuid: &str, request: &ModifyStorageRequest) -> Result<StorageDetails, Error>;
+    async fn delete_storage(&self, uuid: &str) -> Result<(), Error>;
+    async fn attach_storage(&self, server_uuid: &str, request: &AttachStorageRequest) -> Result<ServerDetails, Error>;
+    async fn detach_storage(&self, server_uuid: &str, request: &DetachStorageRequest) -> Result<ServerDetails, Error>;
+    async fn clone_storage(&self, uuid: &str, request: &CloneStorageRequest) -> Result<StorageDetails, Error>;
+    async fn templatize_storage(&self, uuid: &str, request: &TemplatizeStorageRequest) -> Result<StorageDetails, Error>;
+    async fn cancel_storage_operation(&self, uuid: &str) -> Result<(), Error>;
+    async fn create_storage_import(&self, uuid: &str, request: &CreateStorageImportRequest) -> Result<StorageImportDetails, Error>;
+    async fn get_storage_import(&self, uuid: &str) -> Result<StorageImportDetails, Error>;
+    async fn cancel_storage_import(&self, uuid: &str) -> Result<StorageImportDetails, Error>;
+    async fn upload_storage_import(
+        &self,
+        direct_upload_url: &str,
+        body: reqwest::Body,
+        content_type: &str,
+    ) -> Result<StorageImportUploadResult, Error>;
+    async fn create_backup(&self, uuid: &str, request: &CreateBackupRequest) -> Result<StorageDetails, Error>;
+    async fn restore_backup(&self, backup_uuid: &str) -> Result<(), Error>;
+    async fn list_backups(&self, storage_uuid: &str) -> Result<StorageList, Error>;
+    async fn resolve_storage(&self, reference: &StorageRef) -> Result<StorageDetails, Error>;
+    async fn wait_for_storage_state(
+        &self,
+        uuid: &str,
+        desired_state: Option<&StorageState>,
+        undesired_state: Option<&StorageState>,
+        timeout: Duration,
+    ) -> Result<StorageDetails, Error>;
+}
+
+#[async_trait]
+impl StorageOperations for Client {
+    async fn list_storages(&self) -> Result<StorageList, Error> {
+        let response = self.get("/storage").await?;
+        let details: GetStorageResponse = serde_json::from_str(&response)?;
+        Ok(details.storages)
+    }
+
+    async fn list_storages_page(&self, page: &Page) -> Result<StorageList, Error> {
+        let response = self.get(&format!("/storage?{}", page.to_query_params())).await?;
+        let details: GetStorageResponse = serde_json::from_str(&response)?;
+        Ok(details.storages)
+    }
+
+    fn stream_storages(&self, page_size: u32) -> BoxStream<'_, Result<Storage, Error>> {
+        paginate(page_size, move |page| async move {
+            Ok(self.list_storages_page(&page).await?.storage)
+        })
+        .boxed()
+    }
+
+    async fn list_storages_by_type(&self, storage_type: &StorageType) -> Result<StorageList, Error> {
+        let response = self.get(&format!("/storage/{}", storage_type.as_str())).await?;
+        let details: GetStorageResponse = serde_json::from_str(&response)?;
+        Ok(details.storages)
+    }
+
+    async fn list_storages_by_access(&self, access: &StorageAccess) -> Result<StorageList, Error> {
+        let response = self.get(&format!("/storage/{}", access.as_str())).await?;
+        let details: GetStorageResponse = serde_json::from_str(&response)?;
+        Ok(details.storages)
+    }
+
+    async fn list_storages_by_labels(&self, filter: &LabelFilter) -> Result<StorageList, Error> {
+        let query = filter.to_query_params();
+        let path = if query.is_empty() {
+            "/storage".to_string()
+        } else {
+            format!("/storage?{}", query)
+        };
+
+        let response = self.get(&path).await?;
+        let details: GetStorageResponse = serde_json::from_str(&response)?;
+        Ok(details.storages)
+    }
+
+    async fn get_storage(&self, uuid: &str) -> Result<StorageDetails, Error> {
+        let response = self.get(&format!("/storage/{}", uuid)).await?;
+        let details: GetStorageDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.storage)
+    }
+
+    async fn create_storage(&self, request: &CreateStorageRequest) -> Result<StorageDetails, Error> {
+        let response = self.post("/storage", Some(request)).await?;
+        let details: GetStorageDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.storage)
+    }
+
+    async fn modify_storage(&self, uuid: &str, request: &ModifyStorageRequest) -> Result<StorageDetails, Error> {
+        let response = self.put(&format!("/storage/{}", uuid), Some(request)).await?;
+        let details: GetStorageDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.storage)
+    }
+
+    async fn delete_storage(&self, uuid: &str) -> Result<(), Error> {
+        self.delete(&format!("/storage/{}", uuid)).await?;
+        Ok(())
+    }
+
+    async fn attach_storage(&self, server_uuid: &str, request: &AttachStorageRequest) -> Result<ServerDetails, Error> {
+        let response = self.post(&format!("/server/{}/storage/attach", server_uuid), Some(request)).await?;
+        let details: GetServerDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.server)
+    }
+
+    async fn detach_storage(&self, server_uuid: &str, request: &DetachStorageRequest) -> Result<ServerDetails, Error> {
+        let response = self.post(&format!("/server/{}/storage/detach", server_uuid), Some(request)).await?;
+        let details: GetServerDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.server)
+    }
+
+    async fn clone_storage(&self, uuid: &str, request: &CloneStorageRequest) -> Result<StorageDetails, Error> {
+        let response = self.post(&format!("/storage/{}/clone", uuid), Some(request)).await?;
+        let details: GetStorageDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.storage)
+    }
+
+    async fn templatize_storage(&self, uuid: &str, request: &TemplatizeStorageRequest) -> Result<StorageDetails, Error> {
+        let response = self.post(&format!("/storage/{}/templatize", uuid), Some(request)).await?;
+        let details: GetStorageDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.storage)
+    }
+
+    async fn cancel_storage_operation(&self, uuid: &str) -> Result<(), Error> {
+        self.post(&format!("/storage/{}/cancel", uuid), Option::<&()>::None).await?;
+        Ok(())
+    }
+
+    async fn create_storage_import(&self, uuid: &str, request: &CreateStorageImportRequest) -> Result<StorageImportDetails, Error> {
+        let response = self.post(&format!("/storage/{}/import", uuid), Some(request)).await?;
+        let details: StorageImportResponse = serde_json::from_str(&response)?;
+        Ok(details.storage_import)
+    }
+
+    async fn get_storage_import(&self, uuid: &str) -> Result<StorageImportDetails, Error> {
+        let response = self.get(&format!("/storage/{}/import", uuid)).await?;
+        let details: StorageImportResponse = serde_json::from_str(&response)?;
+        Ok(details.storage_import)
+    }
+
+    async fn cancel_storage_import(&self, uuid: &str) -> Result<StorageImportDetails, Error> {
+        let response = self.post(&format!("/storage/{}/import/cancel", uuid), Option::<&()>::None).await?;
+        let details: StorageImportResponse = serde_json::from_str(&response)?;
+        Ok(details.storage_import)
+    }
+
+    async fn upload_storage_import(
+        &self,
+        direct_upload_url: &str,
+        body: reqwest::Body,
+        content_type: &str,
+    ) -> Result<StorageImportUploadResult, Error> {
+        let response = self.put_raw(direct_upload_url, body, content_type).await?;
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    async fn create_backup(&self, uuid: &str, request: &CreateBackupRequest) -> Result<StorageDetails, Error> {
+        let response = self.post(&format!("/storage/{}/backup", uuid), Some(request)).await?;
+        let details: GetStorageDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.storage)
+    }
+
+    async fn restore_backup(&self, backup_uuid: &str) -> Result<(), Error> {
+        self.post(&format!("/storage/{}/restore", backup_uuid), Option::<&()>::None).await?;
+        Ok(())
+    }
+
+    async fn list_backups(&self, storage_uuid: &str) -> Result<StorageList, Error> {
+        let mut backups = self.list_storages_by_type(&StorageType::Backup).await?;
+        backups.storage.retain(|backup| {
+            backup.origin.as_ref().is_some_and(|origin| origin.uuid() == storage_uuid)
+        });
+        Ok(backups)
+    }
+
+    async fn resolve_storage(&self, reference: &StorageRef) -> Result<StorageDetails, Error> {
+        self.get_storage(reference.uuid()).await
+    }
+
+    async fn wait_for_storage_state(
+        &self,
+        uuid: &str,
+        desired_state: Option<&StorageState>,
+        undesired_state: Option<&StorageState>,
+        timeout: Duration,
+    ) -> Result<StorageDetails, Error> {
+        let start = std::time::Instant::now();
+
+        loop {
+            if start.elapsed() > timeout {
+                return Err(Error::Timeout);
+            }
+
+            let res = self.get_storage(uuid).await?;
+
+            match (desired_state, undesired_state) {
+                (Some(desired), _) if res.storage.state == desired.as_str() => return Ok(res),
+                (_, Some(undesired)) if res.storage.state != undesired.as_str() => return Ok(res),
+                _ => {
+                    sleep(Duration::from_secs(5)).await;
+                    continue;
+                }
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_list_storages_by_type() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/storage/normal")
+        .with_status(200)
+        .with_body(r#"{"storages":{"storage":[{"access":"private","created":"2024-01-10T12:00:00Z","encrypted":"yes","labels":{"label":[{"key":"env","value":"prod"}]},"license":0,"part_of_plan":"no","size":10,"state":"online","tier":"maxiops","title":"Operating system disk","type":"normal","uuid":"01eff7ad-168e-413e-83b0-054f6a28fa23","zone":"fi-hel1"}]}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let result = client.list_storages_by_type(&StorageType::Normal).await.unwrap();
+    assert_eq!(result.storage.len(), 1);
+    assert_eq!(result.storage[0].uuid, "01eff7ad-168e-413e-83b0-054f6a28fa23");
+    assert!(result.storage[0].encrypted);
+    assert_eq!(result.storage[0].size, 10);
+}
+
+#[tokio::test]
+async fn test_get_storage() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/storage/01eff7ad-168e-413e-83b0-054f6a28fa23")
+        .with_status(200)
+        .with_body(r#"{"storage":{"access":"private","backup_rule":{"interval":"daily","time":"0430","retention":7},"backups":{"backup":["01f3286c-a5ea-4670-8121-d0b9767d625b"]},"encrypted":"no","license":0,"servers":{"server":["00798b85-efdc-41ca-8021-f6ef457b8531"]},"size":10,"state":"online","tier":"maxiops","title":"Operating system disk","type":"normal","uuid":"01eff7ad-168e-413e-83b0-054f6a28fa23","zone":"fi-hel1"}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let result = client.get_storage("01eff7ad-168e-413e-83b0-054f6a28fa23").await.unwrap();
+    assert!(!result.storage.encrypted);
+    assert_eq!(result.backup_rule, Some(BackupRule::new(BackupRuleInterval::Daily, "0430", 7)));
+    assert_eq!(result.backups.backup, vec![StorageRef("01f3286c-a5ea-4670-8121-d0b9767d625b".to_string())]);
+    assert_eq!(result.servers.server, vec!["00798b85-efdc-41ca-8021-f6ef457b8531"]);
+}
+
+#[tokio::test]
+async fn test_clone_storage_and_wait() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _clone = server.mock("POST", "/1.3/storage/01eff7ad-168e-413e-83b0-054f6a28fa23/clone")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"storage":{"zone":"fi-hel1","title":"Golden image","tier":"maxiops"}})))
+        .with_status(201)
+        .with_body(r#"{"storage":{"access":"private","license":0,"size":10,"state":"maintenance","tier":"maxiops","title":"Golden image","type":"normal","uuid":"01a9e9ba-1f6d-4a4f-9e4b-c1ed0bb3b3a4","zone":"fi-hel1"}}"#)
+        .create();
+    let _get = server.mock("GET", "/1.3/storage/01a9e9ba-1f6d-4a4f-9e4b-c1ed0bb3b3a4")
+        .with_status(200)
+        .with_body(r#"{"storage":{"access":"private","license":0,"size":10,"state":"online","tier":"maxiops","title":"Golden image","type":"normal","uuid":"01a9e9ba-1f6d-4a4f-9e4b-c1ed0bb3b3a4","zone":"fi-hel1"}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let request = CloneStorageRequest::new("fi-hel1", "Golden image").with_tier(STORAGE_TIER_MAXIOPS);
+    let clone = client.clone_storage("01eff7ad-168e-413e-83b0-054f6a28fa23", &request).await.unwrap();
+    assert_eq!(clone.storage.state, "maintenance");
+
+    let result = client.wait_for_storage_state(
+        &clone.storage.uuid,
+        Some(&StorageState::Online),
+        None,
+        Duration::from_secs(10),
+    ).await.unwrap();
+    assert_eq!(result.storage.state, "online");
+}
+
+#[tokio::test]
+async fn test_list_backups() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/storage/backup")
+        .with_status(200)
+        .with_body(r#"{"storages":{"storage":[{"access":"private","created":"2024-01-10T04:30:00Z","license":0,"origin":"01eff7ad-168e-413e-83b0-054f6a28fa23","size":10,"state":"online","title":"Nightly","type":"backup","uuid":"01f3286c-a5ea-4670-8121-d0b9767d625b","zone":"fi-hel1"},{"access":"private","license":0,"origin":"012580a1-32a1-466e-a323-689ca16f2d43","size":20,"state":"online","title":"Other","type":"backup","uuid":"01c6a5cc-9b54-4ad4-9fc1-0e3e50d2f8a2","zone":"fi-hel1"}]}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let result = client.list_backups("01eff7ad-168e-413e-83b0-054f6a28fa23").await.unwrap();
+    assert_eq!(result.storage.len(), 1);
+    assert_eq!(result.storage[0].uuid, "01f3286c-a5ea-4670-8121-d0b9767d625b");
+    assert_eq!(
+        result.storage[0].origin.as_ref().map(StorageRef::uuid),
+        Some("01eff7ad-168e-413e-83b0-054f6a28fa23")
+    );
+}
+
+#[test]
+fn test_storage_encrypted_round_trip() {
+    let storage: Storage = serde_json::from_str(r#"{"access":"private","encrypted":"yes","size":10,"state":"online","title":"data","type":"normal","uuid":"01eff7ad-168e-413e-83b0-054f6a28fa23"}"#).unwrap();
+    let value = serde_json::to_value(&storage).unwrap();
+    assert_eq!(value["encrypted"], "yes");
+
+    let storage: Storage = serde_json::from_value(value).unwrap();
+    assert!(storage.encrypted);
+}
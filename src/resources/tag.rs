@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::tag::*,
+    types::server::{GetServerDetailsResponse, ServerDetails},
+    client::Client,
+};
+
+/// Operations for managing server tags.
+///
+/// Tags must exist before they can be assigned to a server.
+#[async_trait]
+pub trait TagOperations {
+    async fn list_tags(&self) -> Result<TagList, Error>;
+    async fn create_tag(&self, request: &CreateTagRequest) -> Result<Tag, Error>;
+    async fn modify_tag(&self, name: &str, request: &ModifyTagRequest) -> Result<Tag, Error>;
+    async fn delete_tag(&self, name: &str) -> Result<(), Error>;
+    async fn tag_server(&self, uuid: &str, tags: &[&str]) -> Result<ServerDetails, Error>;
+    async fn untag_server(&self, uuid: &str, tags: &[&str]) -> Result<ServerDetails, Error>;
+}
+
+#[async_trait]
+impl TagOperations for Client {
+    async fn list_tags(&self) -> Result<TagList, Error> {
+        let response = self.get("/tag").await?;
+        let details: GetTagsResponse = serde_json::from_str(&response)?;
+        Ok(details.tags)
+    }
+
+    async fn create_tag(&self, request: &CreateTagRequest) -> Result<Tag, Error> {
+        let response = self.post("/tag", Some(request)).await?;
+        let details: GetTagResponse = serde_json::from_str(&response)?;
+        Ok(details.tag)
+    }
+
+    async fn modify_tag(&self, name: &str, request: &ModifyTagRequest) -> Result<Tag, Error> {
+        let response = self.put(&format!("/tag/{}", urlencoding::encode(name)), Some(request)).await?;
+        let details: GetTagResponse = serde_json::from_str(&response)?;
+        Ok(details.tag)
+    }
+
+    async fn delete_tag(&self, name: &str) -> Result<(), Error> {
+        self.delete(&format!("/tag/{}", urlencoding::encode(name))).await?;
+        Ok(())
+    }
+
+    async fn tag_server(&self, uuid: &str, tags: &[&str]) -> Result<ServerDetails, Error> {
+        let response = self.post(&format!("/server/{}/tag/{}", uuid, encode_tags(tags)), Option::<&()>::None).await?;
+        let details: GetServerDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.server)
+    }
+
+    async fn untag_server(&self, uuid: &str, tags: &[&str]) -> Result<ServerDetails, Error> {
+        let response = self.post(&format!("/server/{}/untag/{}", uuid, encode_tags(tags)), Option::<&()>::None).await?;
+        let details: GetServerDetailsResponse = serde_json::from_str(&response)?;
+        Ok(details.server)
+    }
+}
+
+// Tags are joined with commas in the path, so each one is encoded on its own.
+fn encode_tags(tags: &[&str]) -> String {
+    tags.iter()
+        .map(|tag| urlencoding::encode(tag))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[tokio::test]
+async fn test_list_tags_empty() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/tag")
+        .with_status(200)
+        .with_body(r#"{"tags":{}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let tags = client.list_tags().await.unwrap();
+    assert!(tags.tag.is_empty());
+}
+
+#[tokio::test]
+async fn test_rename_tag() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("PUT", "/1.3/tag/DEV")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"tag":{"name":"STAGING"}})))
+        .with_status(200)
+        .with_body(r#"{"tag":{"description":"Development servers","name":"STAGING","servers":{"server":["0077fa3d-32db-4b09-9f5f-30d9e9afb565"]}}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let tag = client.modify_tag("DEV", &ModifyTagRequest::new().with_name("STAGING")).await.unwrap();
+    assert_eq!(tag.name, "STAGING");
+    assert_eq!(tag.servers.server.len(), 1);
+}
+
+#[tokio::test]
+async fn test_tag_server() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("POST", "/1.3/server/00798b85-efdc-41ca-8021-f6ef457b8531/tag/PROD,DB%2FPRIMARY")
+        .with_status(200)
+        .with_body(r#"{"server":{"core_number":"1","hostname":"fi.example.com","license":0,"memory_amount":"2048","plan":"1xCPU-2GB","remote_access_enabled":"no","remote_access_password":"","state":"started","tags":{"tag":["PROD","DB/PRIMARY"]},"title":"Helsinki server","uuid":"00798b85-efdc-41ca-8021-f6ef457b8531","zone":"fi-hel1"}}"#)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let details = client.tag_server("00798b85-efdc-41ca-8021-f6ef457b8531", &["PROD", "DB/PRIMARY"]).await.unwrap();
+    assert_eq!(details.server.tags.tag, vec!["PROD", "DB/PRIMARY"]);
+}
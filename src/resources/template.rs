@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    types::storage::{Storage, StorageAccess, StorageList, StorageType},
+    types::template::TemplateFilter,
+    resources::storage::StorageOperations,
+    client::Client,
+};
+
+/// Operations for looking up public OS templates and private templates.
+///
+/// The UUID of a template found here can be passed to
+/// [`CreateServerStorageDevice::from_template`](crate::types::server::CreateServerStorageDevice::from_template).
+#[async_trait]
+pub trait TemplateOperations {
+    /// Lists both public and private templates.
+    async fn list_templates(&self) -> Result<StorageList, Error>;
+    async fn list_public_templates(&self) -> Result<StorageList, Error>;
+    async fn list_private_templates(&self) -> Result<StorageList, Error>;
+    async fn find_templates(&self, filter: &TemplateFilter) -> Result<Vec<Storage>, Error>;
+    /// Returns the template whose title matches exactly, or failing that,
+    /// the first template whose title starts with the given text.
+    async fn find_template_by_title(&self, title: &str) -> Result<Option<Storage>, Error>;
+}
+
+#[async_trait]
+impl TemplateOperations for Client {
+    async fn list_templates(&self) -> Result<StorageList, Error> {
+        self.list_storages_by_type(&StorageType::Template).await
+    }
+
+    async fn list_public_templates(&self) -> Result<StorageList, Error> {
+        let mut templates = self.list_templates().await?;
+        templates.storage.retain(|t| t.access == StorageAccess::Public.as_str());
+        Ok(templates)
+    }
+
+    async fn list_private_templates(&self) -> Result<StorageList, Error> {
+        let mut templates = self.list_templates().await?;
+        templates.storage.retain(|t| t.access == StorageAccess::Private.as_str());
+        Ok(templates)
+    }
+
+    async fn find_templates(&self, filter: &TemplateFilter) -> Result<Vec<Storage>, Error> {
+        let templates = self.list_templates().await?;
+        Ok(templates.storage.into_iter().filter(|t| filter.matches(t)).collect())
+    }
+
+    async fn find_template_by_title(&self, title: &str) -> Result<Option<Storage>, Error> {
+        let mut matches = self.find_templates(&TemplateFilter::new().with_title(title)).await?;
+        if let Some(index) = matches.iter().position(|t| t.title.eq_ignore_ascii_case(title)) {
+            return Ok(Some(matches.swap_remove(index)));
+        }
+        Ok(matches.into_iter().next())
+    }
+}
+
+#[tokio::test]
+async fn test_find_templates() {
+    use crate::config;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let _mock = server.mock("GET", "/1.3/storage/template")
+        .with_status(200)
+        .with_body(r#"{"storages":{"storage":[
+            {"access":"public","license":0,"size":4,"state":"online","template_type":"cloud-init","title":"Ubuntu Server 22.04 LTS (Jammy Jellyfish)","type":"template","uuid":"01000000-0000-4000-8000-000030220200"},
+            {"access":"public","license":0,"size":4,"state":"online","template_type":"cloud-init","title":"Ubuntu Server 24.04 LTS (Noble Numbat)","type":"template","uuid":"01000000-0000-4000-8000-000030240200"},
+            {"access":"public","license":0,"size":4,"state":"online","template_type":"cloud-init","title":"Debian GNU/Linux 12 (Bookworm)","type":"template","uuid":"01000000-0000-4000-8000-000020070100"},
+            {"access":"private","license":0,"size":10,"state":"online","template_type":"native","title":"web-golden-image","type":"template","uuid":"01a2b3c4-0000-4000-8000-000000000001"}
+        ]}}"#)
+        .expect(3)
+        .create();
+
+    let client = Client::with_config(
+        config::Config::new("foo", "bar")
+            .with_base_url(url)
+    ).unwrap();
+
+    let ubuntu = client.find_template_by_title("Ubuntu Server 24.04").await.unwrap().unwrap();
+    assert_eq!(ubuntu.uuid, "01000000-0000-4000-8000-000030240200");
+
+    let debian = client.find_templates(
+        &TemplateFilter::new().with_os_family("debian").with_version("12")
+    ).await.unwrap();
+    assert_eq!(debian.len(), 1);
+    assert_eq!(debian[0].template_type.as_deref(), Some("cloud-init"));
+
+    let private = client.list_private_templates().await.unwrap();
+    assert_eq!(private.storage.len(), 1);
+    assert_eq!(private.storage[0].title, "web-golden-image");
+}
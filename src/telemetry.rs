@@ -0,0 +1,111 @@
+//! Request instrumentation. Everything here compiles to no-ops unless the
+//! `tracing` feature is enabled.
+
+use std::time::Duration;
+
+/// Keys whose values are replaced before a request body is logged.
+#[cfg(feature = "tracing")]
+const REDACTED_KEYS: &[&str] = &[
+    "password",
+    "remote_access_password",
+    "ssh_key",
+    "ssh_keys",
+    "user_data",
+    "token",
+    "secret_access_key",
+    "private_key",
+    "kubeconfig",
+];
+
+#[cfg(feature = "tracing")]
+pub(crate) fn request_span(method: &reqwest::Method, path: &str) -> tracing::Span {
+    tracing::debug_span!(
+        "upcloud_request",
+        method = %method,
+        path = %path,
+        status = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+        request_id = tracing::field::Empty,
+    )
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn request_body<T: serde::Serialize>(body: &T) {
+    if !tracing::enabled!(tracing::Level::TRACE) {
+        return;
+    }
+    match serde_json::to_value(body) {
+        Ok(mut value) => {
+            redact(&mut value);
+            tracing::trace!(body = %value, "request body");
+        }
+        Err(e) => tracing::trace!(error = %e, "request body could not be serialized"),
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn request_body<T: serde::Serialize>(_body: &T) {}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn response(response: &reqwest::Response, latency: Duration, attempt: u32) {
+    let status = response.status().as_u16();
+    let request_id = response.headers()
+        .get("x-request-id")
+        .and_then(|v| v.to_str().ok());
+
+    let span = tracing::Span::current();
+    span.record("status", status);
+    span.record("latency_ms", latency.as_millis() as u64);
+    if let Some(request_id) = request_id {
+        span.record("request_id", request_id);
+    }
+    tracing::debug!(status, latency_ms = latency.as_millis() as u64, attempt, "response received");
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn response(_response: &reqwest::Response, _latency: Duration, _attempt: u32) {}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn request_failed(error: &reqwest::Error, latency: Duration, attempt: u32) {
+    tracing::debug!(error = %error, latency_ms = latency.as_millis() as u64, attempt, "request failed");
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn request_failed(_error: &reqwest::Error, _latency: Duration, _attempt: u32) {}
+
+#[cfg(feature = "tracing")]
+fn redact(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if REDACTED_KEYS.contains(&key.as_str()) {
+                    *value = serde_json::Value::String("<redacted>".to_string());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_redact_create_server_request() {
+    use crate::types::server::{CreateServerRequest, LoginUser};
+
+    let request = CreateServerRequest::new()
+        .with_login_user(LoginUser::new("deploy").with_ssh_key("ssh-ed25519 AAAA"))
+        .with_user_data("#!/bin/sh\necho secret".to_string())
+        .with_remote_access(true, None, Some("hunter2".to_string()))
+        .build();
+
+    let mut value = serde_json::to_value(&request).unwrap();
+    redact(&mut value);
+    let logged = value.to_string();
+    assert!(!logged.contains("ssh-ed25519"));
+    assert!(!logged.contains("echo secret"));
+    assert!(!logged.contains("hunter2"));
+    assert!(logged.contains("deploy"));
+}
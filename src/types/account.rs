@@ -0,0 +1,367 @@
+use serde::{Deserialize, Serialize};
+use crate::types::server::{deserialize_yes_no_as_bool, deserialize_yes_no_as_option_bool, serialize_bool_as_yes_no};
+
+pub const ACCOUNT_ROLE_API: &str = "api";
+pub const ACCOUNT_ROLE_BILLING: &str = "billing";
+pub const ACCOUNT_ROLE_TECHNICAL: &str = "technical";
+
+pub const ACCOUNT_TYPE_MAIN: &str = "main";
+pub const ACCOUNT_TYPE_SUB: &str = "sub";
+
+#[derive(Debug, Deserialize)]
+pub struct GetAccountResponse {
+    pub account: Account,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Account {
+    pub credits: f64,
+    pub username: String,
+    pub resource_limits: ResourceLimits,
+}
+
+/// Resource limits of the account. Memory is in megabytes and storage in
+/// gigabytes.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ResourceLimits {
+    pub cores: i64,
+    pub memory: i64,
+    pub detached_floating_ips: i64,
+    pub gpus: i64,
+    pub load_balancers: i64,
+    pub managed_object_storages: i64,
+    pub network_peerings: i64,
+    pub networks: i64,
+    pub ntp_excess_gib: i64,
+    pub public_ipv4: i64,
+    pub public_ipv6: i64,
+    pub storage_hdd: i64,
+    pub storage_maxiops: i64,
+    pub storage_ssd: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetAccountListResponse {
+    pub accounts: AccountList,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountList {
+    pub account: Vec<AccountListItem>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountListItem {
+    pub username: String,
+    #[serde(rename = "type")]
+    pub account_type: String,
+    #[serde(default)]
+    pub roles: Roles,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Roles {
+    pub role: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IpFilters {
+    pub ip_filter: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagAccess {
+    pub tag: Vec<TagAccessItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagAccessItem {
+    pub name: String,
+    #[serde(serialize_with = "serialize_bool_as_yes_no", deserialize_with = "deserialize_yes_no_as_option_bool", default)]
+    pub storage: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ServerAccess {
+    pub server: Vec<ServerAccessItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerAccessItem {
+    pub uuid: String,
+    #[serde(serialize_with = "serialize_bool_as_yes_no", deserialize_with = "deserialize_yes_no_as_option_bool", default)]
+    pub storage: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StorageAccess {
+    pub storage: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NetworkAccess {
+    pub network: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetAccountDetailsResponse {
+    pub account: AccountDetails,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AccountDetails {
+    pub main_account: String,
+    #[serde(rename = "type")]
+    pub account_type: String,
+    pub username: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub company: String,
+    pub address: String,
+    pub postal_code: String,
+    pub city: String,
+    pub email: String,
+    pub phone: String,
+    pub state: String,
+    pub country: String,
+    pub currency: String,
+    pub language: String,
+    pub vat_number: String,
+    pub timezone: String,
+    #[serde(deserialize_with = "deserialize_yes_no_as_bool")]
+    pub allow_api: bool,
+    #[serde(deserialize_with = "deserialize_yes_no_as_bool")]
+    pub allow_gui: bool,
+    pub roles: Roles,
+    pub ip_filters: IpFilters,
+    pub tag_access: TagAccess,
+    pub server_access: ServerAccess,
+    pub storage_access: StorageAccess,
+    pub network_access: NetworkAccess,
+}
+
+/// Fields shared by sub-account creation and account modification. Unset
+/// fields are left unchanged.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AccountRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(serialize_with = "serialize_bool_as_yes_no", skip_serializing_if = "Option::is_none")]
+    pub allow_api: Option<bool>,
+    #[serde(serialize_with = "serialize_bool_as_yes_no", skip_serializing_if = "Option::is_none")]
+    pub allow_gui: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Roles>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_filters: Option<IpFilters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_access: Option<TagAccess>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_access: Option<ServerAccess>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_access: Option<StorageAccess>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_access: Option<NetworkAccess>,
+}
+
+impl AccountRequest {
+    pub fn with_name(mut self, first_name: impl Into<String>, last_name: impl Into<String>) -> Self {
+        self.first_name = Some(first_name.into());
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    pub fn with_company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(company.into());
+        self
+    }
+
+    pub fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Phone number in international format, e.g. `+358.91234567`.
+    pub fn with_phone(mut self, phone: impl Into<String>) -> Self {
+        self.phone = Some(phone.into());
+        self
+    }
+
+    pub fn with_country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    pub fn with_currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    pub fn with_timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    pub fn with_allow_api(mut self, allow: bool) -> Self {
+        self.allow_api = Some(allow);
+        self
+    }
+
+    pub fn with_allow_gui(mut self, allow: bool) -> Self {
+        self.allow_gui = Some(allow);
+        self
+    }
+
+    pub fn with_role(mut self, role: impl Into<String>) -> Self {
+        self.roles.get_or_insert_with(Roles::default).role.push(role.into());
+        self
+    }
+
+    pub fn with_ip_filter(mut self, filter: impl Into<String>) -> Self {
+        self.ip_filters.get_or_insert_with(IpFilters::default).ip_filter.push(filter.into());
+        self
+    }
+
+    pub fn with_tag_access(mut self, tag: impl Into<String>, storage: bool) -> Self {
+        self.tag_access.get_or_insert_with(TagAccess::default).tag.push(TagAccessItem {
+            name: tag.into(),
+            storage: Some(storage),
+        });
+        self
+    }
+
+    pub fn with_server_access(mut self, uuid: impl Into<String>, storage: bool) -> Self {
+        self.server_access.get_or_insert_with(ServerAccess::default).server.push(ServerAccessItem {
+            uuid: uuid.into(),
+            storage: Some(storage),
+        });
+        self
+    }
+
+    pub fn with_storage_access(mut self, uuid: impl Into<String>) -> Self {
+        self.storage_access.get_or_insert_with(StorageAccess::default).storage.push(uuid.into());
+        self
+    }
+
+    pub fn with_network_access(mut self, uuid: impl Into<String>) -> Self {
+        self.network_access.get_or_insert_with(NetworkAccess::default).network.push(uuid.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateSubAccountRequest {
+    pub sub_account: AccountRequest,
+}
+
+impl CreateSubAccountRequest {
+    pub fn new(username: impl Into<String>, password: impl Into<String>, account: AccountRequest) -> Self {
+        Self {
+            sub_account: AccountRequest {
+                username: Some(username.into()),
+                password: Some(password.into()),
+                ..account
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModifyAccountRequest {
+    pub account: AccountRequest,
+}
+
+impl ModifyAccountRequest {
+    pub fn new(account: AccountRequest) -> Self {
+        Self { account }
+    }
+}
+
+pub const PERMISSION_TARGET_SERVER: &str = "server";
+pub const PERMISSION_TARGET_STORAGE: &str = "storage";
+pub const PERMISSION_TARGET_NETWORK: &str = "network";
+pub const PERMISSION_TARGET_ROUTER: &str = "router";
+pub const PERMISSION_TARGET_OBJECT_STORAGE: &str = "object_storage";
+pub const PERMISSION_TARGET_MANAGED_DATABASE: &str = "managed_database";
+pub const PERMISSION_TARGET_MANAGED_LOAD_BALANCER: &str = "managed_loadbalancer";
+pub const PERMISSION_TARGET_MANAGED_KUBERNETES: &str = "managed_kubernetes";
+pub const PERMISSION_TARGET_TAG_ACCESS: &str = "tag_access";
+
+#[derive(Debug, Deserialize)]
+pub struct GetPermissionsResponse {
+    pub permissions: PermissionList,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PermissionList {
+    pub permission: Vec<Permission>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PermissionOptions {
+    #[serde(serialize_with = "serialize_bool_as_yes_no", deserialize_with = "deserialize_yes_no_as_option_bool", skip_serializing_if = "Option::is_none", default)]
+    pub storage: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Permission {
+    pub user: String,
+    pub target_type: String,
+    pub target_identifier: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<PermissionOptions>,
+}
+
+impl Permission {
+    pub fn new(user: impl Into<String>, target_type: impl Into<String>, target_identifier: impl Into<String>) -> Self {
+        Self {
+            user: user.into(),
+            target_type: target_type.into(),
+            target_identifier: target_identifier.into(),
+            options: None,
+        }
+    }
+
+    /// Also grants access to the storages attached to the target server.
+    pub fn with_storage(mut self, storage: bool) -> Self {
+        self.options = Some(PermissionOptions { storage: Some(storage) });
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PermissionRequest {
+    pub permission: Permission,
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use crate::types::server::deserialize_yes_no_as_bool;
+
+pub const PLAN_CUSTOM: &str = "custom";
+
+#[derive(Debug, Deserialize)]
+pub struct GetZonesResponse {
+    pub zones: ZoneList,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ZoneList {
+    pub zone: Vec<Zone>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Zone {
+    pub id: String,
+    pub description: String,
+    #[serde(deserialize_with = "deserialize_yes_no_as_bool", default)]
+    pub public: bool,
+    /// Set for private cloud zones, which are hosted within a public zone.
+    #[serde(default)]
+    pub parent_zone: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetPlansResponse {
+    pub plans: PlanList,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PlanList {
+    pub plan: Vec<Plan>,
+}
+
+/// A preconfigured server plan. Memory is in megabytes, storage in
+/// gigabytes and outbound traffic in megabytes per month.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Plan {
+    pub name: String,
+    pub core_number: i32,
+    pub memory_amount: i32,
+    pub storage_size: i32,
+    pub storage_tier: String,
+    pub public_traffic_out: i64,
+    #[serde(default)]
+    pub gpu_amount: Option<i32>,
+    #[serde(default)]
+    pub gpu_model: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetPricesResponse {
+    pub prices: PriceZoneList,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceZoneList {
+    pub zone: Vec<PriceZone>,
+}
+
+/// Prices of a single zone, keyed by product name such as `firewall`,
+/// `storage_maxiops` or `server_plan_1xCPU-1GB`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceZone {
+    pub name: String,
+    #[serde(flatten)]
+    pub products: HashMap<String, Price>,
+}
+
+impl PriceZone {
+    pub fn get(&self, product: &str) -> Option<&Price> {
+        self.products.get(product)
+    }
+
+    pub fn server_plan(&self, plan: &str) -> Option<&Price> {
+        self.products.get(&format!("server_plan_{}", plan))
+    }
+}
+
+/// Hourly price in cents for `amount` units of a product.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Price {
+    pub amount: i64,
+    pub price: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetTimezonesResponse {
+    pub timezones: TimezoneList,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimezoneList {
+    pub timezone: Vec<String>,
+}
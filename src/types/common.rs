@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+use crate::error::Error;
+
+pub const LABEL_KEY_MIN_LENGTH: usize = 2;
+pub const LABEL_KEY_MAX_LENGTH: usize = 32;
+pub const LABEL_VALUE_MAX_LENGTH: usize = 255;
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Labels {
+    #[serde(default)]
     pub label: Vec<Label>,
 }
 
@@ -20,6 +27,121 @@ impl Labels {
         self.label.push(Label { key: key.to_string(), value: value.to_string() });
         self
     }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.label.iter().find(|l| l.key == key).map(|l| l.value.as_str())
+    }
+
+    /// Sets the value of a label, replacing any existing label with the
+    /// same key.
+    pub fn set(&mut self, key: &str, value: &str) {
+        match self.label.iter_mut().find(|l| l.key == key) {
+            Some(label) => label.value = value.to_string(),
+            None => self.label.push(Label { key: key.to_string(), value: value.to_string() }),
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) {
+        self.label.retain(|l| l.key != key);
+    }
+
+    /// Validates every label and checks that no key appears twice.
+    pub fn validate(&self) -> Result<(), Error> {
+        for (i, label) in self.label.iter().enumerate() {
+            label.validate()?;
+            if self.label[..i].iter().any(|l| l.key == label.key) {
+                return Err(Error::ValidationError(format!("duplicate label key '{}'", label.key)));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Label {
+    /// Checks the label against the UpCloud rules: keys are 2-32 ASCII
+    /// letters, digits, `-` or `_` and may not start with `_`; values are at
+    /// most 255 characters without control characters.
+    pub fn validate(&self) -> Result<(), Error> {
+        let key_len = self.key.chars().count();
+        if !(LABEL_KEY_MIN_LENGTH..=LABEL_KEY_MAX_LENGTH).contains(&key_len) {
+            return Err(Error::ValidationError(format!(
+                "label key '{}' must be {}-{} characters long",
+                self.key, LABEL_KEY_MIN_LENGTH, LABEL_KEY_MAX_LENGTH
+            )));
+        }
+        if !self.key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(Error::ValidationError(format!(
+                "label key '{}' may only contain letters, digits, '-' and '_'",
+                self.key
+            )));
+        }
+        if self.key.starts_with('_') {
+            return Err(Error::ValidationError(format!("label key '{}' may not start with '_'", self.key)));
+        }
+        if self.value.chars().count() > LABEL_VALUE_MAX_LENGTH {
+            return Err(Error::ValidationError(format!(
+                "value of label '{}' must be at most {} characters long",
+                self.key, LABEL_VALUE_MAX_LENGTH
+            )));
+        }
+        if self.value.chars().any(char::is_control) {
+            return Err(Error::ValidationError(format!(
+                "value of label '{}' may not contain control characters",
+                self.key
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Resource types that support labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelledResource {
+    Server,
+    Storage,
+    Network,
+    Router,
+    LoadBalancer,
+    Database,
+}
+
+impl LabelledResource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Server => "server",
+            Self::Storage => "storage",
+            Self::Network => "network",
+            Self::Router => "router",
+            Self::LoadBalancer => "load-balancer",
+            Self::Database => "database",
+        }
+    }
+}
+
+/// Labels of an API 1.3 resource, e.g. `{"labels": {"label": [..]}}`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LabelsBody {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Labels>,
+}
+
+/// Label request and response body of API 1.3 resources, keyed by the
+/// resource, e.g. `{"server": {"labels": {"label": [..]}}}`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LabelsWrapper {
+    Server(LabelsBody),
+    Storage(LabelsBody),
+    Network(LabelsBody),
+    Router(LabelsBody),
+}
+
+/// Label request and response body of newer endpoints, which use a flat
+/// `{"labels": [..]}` list.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LabelListBody {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<Label>>,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -64,4 +186,78 @@ impl LabelFilter {
                 .join("&")
         }
     }
-}
\ No newline at end of file
+}
+
+/// A page of a list request, selected with `limit` and `offset` query
+/// parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Page {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl Page {
+    pub fn new(limit: u32, offset: u32) -> Self {
+        Self { limit, offset }
+    }
+
+    /// The first page with the given number of items per page.
+    pub fn first(limit: u32) -> Self {
+        Self::new(limit, 0)
+    }
+
+    pub fn next(&self) -> Self {
+        Self::new(self.limit, self.offset.saturating_add(self.limit))
+    }
+
+    pub fn to_query_params(&self) -> String {
+        format!("limit={}&offset={}", self.limit, self.offset)
+    }
+}
+
+// The API returns an empty string or an empty object instead of omitting
+// unset values, e.g. the `origin` of a storage that is not a backup.
+pub(crate) fn deserialize_empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    match &value {
+        serde_json::Value::Null => Ok(None),
+        serde_json::Value::String(s) if s.is_empty() => Ok(None),
+        serde_json::Value::Object(o) if o.is_empty() => Ok(None),
+        _ => serde_json::from_value(value).map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+// Newer endpoints take labels as a flat `[{"key": .., "value": ..}]` list
+// rather than the `{"label": [..]}` wrapper used by API 1.3 resources. Object
+// storage exposes that list as `Labels` through this module; load balancer,
+// database and Kubernetes types keep a plain `Vec<Label>`.
+pub(crate) mod labels_as_list {
+    use super::{Label, Labels};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(labels: &Option<Labels>, serializer: S) -> Result<S::Ok, S::Error> {
+        match labels {
+            Some(labels) => labels.label.serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Labels>, D::Error> {
+        let label = Option::<Vec<Label>>::deserialize(deserializer)?;
+        Ok(label.map(|label| Labels { label }))
+    }
+}
+
+#[test]
+fn test_label_validation() {
+    assert!(Label { key: "env".to_string(), value: "prod".to_string() }.validate().is_ok());
+    assert!(Label { key: "e".to_string(), value: String::new() }.validate().is_err());
+    assert!(Label { key: "_internal".to_string(), value: String::new() }.validate().is_err());
+    assert!(Label { key: "app.name".to_string(), value: String::new() }.validate().is_err());
+    assert!(Label { key: "env".to_string(), value: "x".repeat(256) }.validate().is_err());
+    assert!(Labels::new().with("env", "a").with("env", "b").validate().is_err());
+}
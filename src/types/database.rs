@@ -0,0 +1,718 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use crate::error::Error;
+use crate::types::common::Label;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseType {
+    Pg,
+    Mysql,
+    Redis,
+    Opensearch,
+}
+
+impl DatabaseType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pg => "pg",
+            Self::Mysql => "mysql",
+            Self::Redis => "redis",
+            Self::Opensearch => "opensearch",
+        }
+    }
+}
+
+pub enum DatabaseState {
+    Running,
+    Poweroff,
+    Rebuilding,
+    Rebalancing,
+}
+
+impl DatabaseState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Poweroff => "poweroff",
+            Self::Rebuilding => "rebuilding",
+            Self::Rebalancing => "rebalancing",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionPoolMode {
+    Session,
+    Transaction,
+    Statement,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseMetricsPeriod {
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl DatabaseMetricsPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ManagedDatabase {
+    pub uuid: String,
+    pub title: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "type")]
+    pub database_type: String,
+    pub plan: String,
+    pub zone: String,
+    pub state: String,
+    #[serde(default)]
+    pub powered: bool,
+    #[serde(default)]
+    pub node_count: i32,
+    #[serde(default)]
+    pub node_states: Vec<DatabaseNodeState>,
+    #[serde(default)]
+    pub service_uri: String,
+    #[serde(default)]
+    pub service_uri_params: Option<DatabaseServiceUriParams>,
+    #[serde(default)]
+    pub maintenance: Option<DatabaseMaintenance>,
+    /// Engine specific properties. Use the typed accessors such as
+    /// [`ManagedDatabase::postgres_properties`] to read them.
+    #[serde(default)]
+    pub properties: serde_json::Value,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub termination_protection: bool,
+    #[serde(default)]
+    pub create_time: String,
+    #[serde(default)]
+    pub update_time: String,
+}
+
+impl ManagedDatabase {
+    pub fn postgres_properties(&self) -> Result<PostgresProperties, serde_json::Error> {
+        serde_json::from_value(self.properties.clone())
+    }
+
+    pub fn mysql_properties(&self) -> Result<MysqlProperties, serde_json::Error> {
+        serde_json::from_value(self.properties.clone())
+    }
+
+    pub fn redis_properties(&self) -> Result<RedisProperties, serde_json::Error> {
+        serde_json::from_value(self.properties.clone())
+    }
+
+    pub fn opensearch_properties(&self) -> Result<OpenSearchProperties, serde_json::Error> {
+        serde_json::from_value(self.properties.clone())
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseNodeState {
+    pub name: String,
+    #[serde(default)]
+    pub role: String,
+    pub state: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseServiceUriParams {
+    #[serde(default)]
+    pub dbname: String,
+    #[serde(default)]
+    pub host: String,
+    #[serde(default)]
+    pub password: String,
+    #[serde(default)]
+    pub port: String,
+    #[serde(default)]
+    pub ssl_mode: String,
+    #[serde(default)]
+    pub user: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatabaseMaintenance {
+    /// Day of week, e.g. `monday`.
+    pub dow: String,
+    /// Time of day in `hh:mm:ss` format.
+    pub time: String,
+}
+
+/// PostgreSQL service properties. Like the other engine property sets,
+/// properties not modelled here can be set and read through `other`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PostgresProperties {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_access: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_filter: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automatic_utility_network_ip_filter: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_hour: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_minute: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_mem: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_buffers_percentage: Option<f64>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MysqlProperties {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_access: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_filter: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automatic_utility_network_ip_filter: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_hour: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_minute: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_time_zone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_require_primary_key: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_timeout: Option<i64>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RedisProperties {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_access: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_filter: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automatic_utility_network_ip_filter: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redis_maxmemory_policy: Option<String>,
+    /// `rdb` or `off`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redis_persistence: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redis_ssl: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redis_timeout: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redis_number_of_databases: Option<i32>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpenSearchProperties {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_access: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_filter: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automatic_utility_network_ip_filter: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_index_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action_auto_create_index_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_domain: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum DatabaseProperties {
+    Postgres(PostgresProperties),
+    Mysql(MysqlProperties),
+    Redis(RedisProperties),
+    OpenSearch(OpenSearchProperties),
+}
+
+impl DatabaseProperties {
+    /// The engine these properties apply to.
+    pub fn database_type(&self) -> DatabaseType {
+        match self {
+            Self::Postgres(_) => DatabaseType::Pg,
+            Self::Mysql(_) => DatabaseType::Mysql,
+            Self::Redis(_) => DatabaseType::Redis,
+            Self::OpenSearch(_) => DatabaseType::Opensearch,
+        }
+    }
+
+    pub(crate) fn validate_for(&self, database_type: &str) -> Result<(), Error> {
+        let expected = self.database_type().as_str();
+        if expected != database_type {
+            return Err(Error::ValidationError(format!(
+                "{} properties cannot be applied to a {} database",
+                expected, database_type,
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl From<PostgresProperties> for DatabaseProperties {
+    fn from(properties: PostgresProperties) -> Self {
+        Self::Postgres(properties)
+    }
+}
+
+impl From<MysqlProperties> for DatabaseProperties {
+    fn from(properties: MysqlProperties) -> Self {
+        Self::Mysql(properties)
+    }
+}
+
+impl From<RedisProperties> for DatabaseProperties {
+    fn from(properties: RedisProperties) -> Self {
+        Self::Redis(properties)
+    }
+}
+
+impl From<OpenSearchProperties> for DatabaseProperties {
+    fn from(properties: OpenSearchProperties) -> Self {
+        Self::OpenSearch(properties)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateDatabaseRequest {
+    #[serde(rename = "type")]
+    pub database_type: DatabaseType,
+    pub hostname_prefix: String,
+    pub title: String,
+    pub plan: String,
+    pub zone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<DatabaseProperties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintenance: Option<DatabaseMaintenance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub termination_protection: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<Label>,
+}
+
+impl CreateDatabaseRequest {
+    pub fn new(
+        database_type: DatabaseType,
+        hostname_prefix: impl Into<String>,
+        plan: impl Into<String>,
+        zone: impl Into<String>,
+    ) -> Self {
+        let hostname_prefix = hostname_prefix.into();
+        Self {
+            database_type,
+            title: hostname_prefix.clone(),
+            hostname_prefix,
+            plan: plan.into(),
+            zone: zone.into(),
+            properties: None,
+            maintenance: None,
+            termination_protection: None,
+            labels: Vec::new(),
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_properties(mut self, properties: impl Into<DatabaseProperties>) -> Self {
+        self.properties = Some(properties.into());
+        self
+    }
+
+    pub fn with_maintenance(mut self, dow: impl Into<String>, time: impl Into<String>) -> Self {
+        self.maintenance = Some(DatabaseMaintenance { dow: dow.into(), time: time.into() });
+        self
+    }
+
+    pub fn with_termination_protection(mut self, enabled: bool) -> Self {
+        self.termination_protection = Some(enabled);
+        self
+    }
+
+    pub fn with_label(mut self, key: &str, value: &str) -> Self {
+        self.labels.push(Label { key: key.to_string(), value: value.to_string() });
+        self
+    }
+
+    /// Checks that the properties, if any, belong to the requested engine.
+    pub fn validate(&self) -> Result<(), Error> {
+        match &self.properties {
+            Some(properties) => properties.validate_for(self.database_type.as_str()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyDatabaseRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub powered: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<DatabaseProperties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintenance: Option<DatabaseMaintenance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub termination_protection: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<Label>>,
+}
+
+impl ModifyDatabaseRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_plan(mut self, plan: impl Into<String>) -> Self {
+        self.plan = Some(plan.into());
+        self
+    }
+
+    pub fn with_zone(mut self, zone: impl Into<String>) -> Self {
+        self.zone = Some(zone.into());
+        self
+    }
+
+    pub fn with_powered(mut self, powered: bool) -> Self {
+        self.powered = Some(powered);
+        self
+    }
+
+    pub fn with_properties(mut self, properties: impl Into<DatabaseProperties>) -> Self {
+        self.properties = Some(properties.into());
+        self
+    }
+
+    pub fn with_maintenance(mut self, dow: impl Into<String>, time: impl Into<String>) -> Self {
+        self.maintenance = Some(DatabaseMaintenance { dow: dow.into(), time: time.into() });
+        self
+    }
+
+    pub fn with_termination_protection(mut self, enabled: bool) -> Self {
+        self.termination_protection = Some(enabled);
+        self
+    }
+
+    pub fn with_label(mut self, key: &str, value: &str) -> Self {
+        self.labels.get_or_insert_with(Vec::new).push(Label { key: key.to_string(), value: value.to_string() });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatabaseLogicalDatabase {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lc_collate: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lc_ctype: Option<String>,
+}
+
+impl DatabaseLogicalDatabase {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_locale(mut self, lc_collate: impl Into<String>, lc_ctype: impl Into<String>) -> Self {
+        self.lc_collate = Some(lc_collate.into());
+        self.lc_ctype = Some(lc_ctype.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseUser {
+    pub username: String,
+    #[serde(default)]
+    pub password: String,
+    #[serde(rename = "type", default)]
+    pub user_type: String,
+    #[serde(default)]
+    pub authentication: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateDatabaseUserRequest {
+    pub username: String,
+    /// A password is generated when not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<String>,
+}
+
+impl CreateDatabaseUserRequest {
+    pub fn new(username: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    pub fn with_authentication(mut self, authentication: impl Into<String>) -> Self {
+        self.authentication = Some(authentication.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyDatabaseUserRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<String>,
+}
+
+impl ModifyDatabaseUserRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    pub fn with_authentication(mut self, authentication: impl Into<String>) -> Self {
+        self.authentication = Some(authentication.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseConnectionPool {
+    pub pool_name: String,
+    pub database: String,
+    pub pool_mode: ConnectionPoolMode,
+    pub pool_size: i32,
+    pub username: String,
+    #[serde(default, skip_serializing)]
+    pub connection_uri: String,
+}
+
+impl DatabaseConnectionPool {
+    pub fn new(
+        pool_name: impl Into<String>,
+        database: impl Into<String>,
+        username: impl Into<String>,
+        pool_size: i32,
+    ) -> Self {
+        Self {
+            pool_name: pool_name.into(),
+            database: database.into(),
+            pool_mode: ConnectionPoolMode::Transaction,
+            pool_size,
+            username: username.into(),
+            connection_uri: String::new(),
+        }
+    }
+
+    pub fn with_pool_mode(mut self, pool_mode: ConnectionPoolMode) -> Self {
+        self.pool_mode = pool_mode;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyConnectionPoolRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_mode: Option<ConnectionPoolMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+impl ModifyConnectionPoolRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.database = Some(database.into());
+        self
+    }
+
+    pub fn with_pool_mode(mut self, pool_mode: ConnectionPoolMode) -> Self {
+        self.pool_mode = Some(pool_mode);
+        self
+    }
+
+    pub fn with_pool_size(mut self, pool_size: i32) -> Self {
+        self.pool_size = Some(pool_size);
+        self
+    }
+
+    pub fn with_username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+}
+
+/// Metrics keyed by chart name, e.g. `cpu_usage` or `disk_usage`.
+pub type DatabaseMetrics = HashMap<String, DatabaseMetricsChart>;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseMetricsChart {
+    pub data: DatabaseMetricsChartData,
+    #[serde(default)]
+    pub hints: DatabaseMetricsChartHints,
+}
+
+/// Chart data as a table: the first column is the timestamp and the rest
+/// one series per node.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseMetricsChartData {
+    #[serde(default)]
+    pub cols: Vec<DatabaseMetricsColumn>,
+    #[serde(default)]
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseMetricsColumn {
+    #[serde(default)]
+    pub label: String,
+    #[serde(rename = "type", default)]
+    pub column_type: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseMetricsChartHints {
+    #[serde(default)]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseLogs {
+    #[serde(default)]
+    pub logs: Vec<DatabaseLogEntry>,
+    /// Offset to pass to the next request to continue reading.
+    #[serde(default)]
+    pub offset: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseLogEntry {
+    pub time: String,
+    pub message: String,
+    #[serde(default)]
+    pub hostname: String,
+    #[serde(default)]
+    pub service: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DatabaseLogsRequest {
+    pub limit: Option<u32>,
+    pub offset: Option<String>,
+    pub descending: bool,
+}
+
+impl DatabaseLogsRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: impl Into<String>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn with_descending(mut self, descending: bool) -> Self {
+        self.descending = descending;
+        self
+    }
+
+    pub fn to_query_params(&self) -> String {
+        let mut params = Vec::new();
+        if let Some(limit) = self.limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = &self.offset {
+            params.push(format!("offset={}", urlencoding::encode(offset)));
+        }
+        params.push(format!("order={}", if self.descending { "desc" } else { "asc" }));
+        params.join("&")
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpgradeDatabaseVersionRequest {
+    pub target_version: String,
+}
+
+impl UpgradeDatabaseVersionRequest {
+    pub fn new(target_version: impl Into<String>) -> Self {
+        Self { target_version: target_version.into() }
+    }
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use crate::types::common::deserialize_empty_as_none;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FirewallRuleDirection {
+    In,
+    Out,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FirewallRuleAction {
+    Accept,
+    Reject,
+    Drop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FirewallRuleFamily {
+    IPv4,
+    IPv6,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FirewallRuleProtocol {
+    Tcp,
+    Udp,
+    Icmp,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetFirewallRulesResponse {
+    pub firewall_rules: FirewallRuleList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetFirewallRuleDetailsResponse {
+    pub firewall_rule: FirewallRule,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FirewallRuleList {
+    #[serde(default)]
+    pub firewall_rule: Vec<FirewallRule>,
+}
+
+/// A single server firewall rule. Unset fields match any value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FirewallRule {
+    pub direction: FirewallRuleDirection,
+    pub action: FirewallRuleAction,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub family: Option<FirewallRuleFamily>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub protocol: Option<FirewallRuleProtocol>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub position: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub icmp_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub source_address_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub source_address_end: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub source_port_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub source_port_end: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub destination_address_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub destination_address_end: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub destination_port_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub destination_port_end: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub comment: Option<String>,
+}
+
+impl FirewallRule {
+    pub fn new(direction: FirewallRuleDirection, action: FirewallRuleAction) -> Self {
+        Self {
+            direction,
+            action,
+            family: None,
+            protocol: None,
+            position: None,
+            icmp_type: None,
+            source_address_start: None,
+            source_address_end: None,
+            source_port_start: None,
+            source_port_end: None,
+            destination_address_start: None,
+            destination_address_end: None,
+            destination_port_start: None,
+            destination_port_end: None,
+            comment: None,
+        }
+    }
+
+    pub fn with_family(mut self, family: FirewallRuleFamily) -> Self {
+        self.family = Some(family);
+        self
+    }
+
+    pub fn with_protocol(mut self, protocol: FirewallRuleProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    pub fn with_position(mut self, position: i32) -> Self {
+        self.position = Some(position.to_string());
+        self
+    }
+
+    pub fn with_icmp_type(mut self, icmp_type: impl Into<String>) -> Self {
+        self.icmp_type = Some(icmp_type.into());
+        self
+    }
+
+    pub fn with_source_address(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.source_address_start = Some(start.into());
+        self.source_address_end = Some(end.into());
+        self
+    }
+
+    pub fn with_source_port(mut self, start: u16, end: u16) -> Self {
+        self.source_port_start = Some(start.to_string());
+        self.source_port_end = Some(end.to_string());
+        self
+    }
+
+    pub fn with_destination_address(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.destination_address_start = Some(start.into());
+        self.destination_address_end = Some(end.into());
+        self
+    }
+
+    pub fn with_destination_port(mut self, start: u16, end: u16) -> Self {
+        self.destination_port_start = Some(start.to_string());
+        self.destination_port_end = Some(end.to_string());
+        self
+    }
+
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateFirewallRuleRequest {
+    pub firewall_rule: FirewallRule,
+}
+
+impl CreateFirewallRuleRequest {
+    pub fn new(firewall_rule: FirewallRule) -> Self {
+        Self { firewall_rule }
+    }
+}
+
+/// An ordered, declarative set of firewall rules.
+///
+/// Applying a ruleset replaces all existing rules of a server. Rules are
+/// evaluated in the order they were added.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FirewallRuleset {
+    pub firewall_rules: FirewallRuleList,
+}
+
+impl FirewallRuleset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rule(mut self, rule: FirewallRule) -> Self {
+        self.firewall_rules.firewall_rule.push(rule);
+        self
+    }
+
+    pub fn rules(&self) -> &[FirewallRule] {
+        &self.firewall_rules.firewall_rule
+    }
+}
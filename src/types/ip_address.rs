@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use crate::types::server::{IPAddress, serialize_bool_as_yes_no};
+
+pub const IP_ADDRESS_FAMILY_IPV4: &str = "IPv4";
+pub const IP_ADDRESS_FAMILY_IPV6: &str = "IPv6";
+
+pub const IP_ADDRESS_ACCESS_PUBLIC: &str = "public";
+pub const IP_ADDRESS_ACCESS_PRIVATE: &str = "private";
+pub const IP_ADDRESS_ACCESS_UTILITY: &str = "utility";
+
+#[derive(Debug, Deserialize)]
+pub struct GetIPAddressesResponse {
+    pub ip_addresses: IPAddressList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetIPAddressDetailsResponse {
+    pub ip_address: IPAddress,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IPAddressList {
+    #[serde(default)]
+    pub ip_address: Vec<IPAddress>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct AssignIPAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_bool_as_yes_no")]
+    pub floating: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct AssignIPAddressRequest {
+    pub ip_address: AssignIPAddress,
+}
+
+impl AssignIPAddressRequest {
+    /// Assign a new IP address to a server.
+    pub fn for_server(server: impl Into<String>) -> Self {
+        Self {
+            ip_address: AssignIPAddress {
+                server: Some(server.into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Reserve a floating IP address in a zone. Attach it with
+    /// [`AssignIPAddressRequest::with_mac`] or later with a modify call.
+    pub fn floating(zone: impl Into<String>) -> Self {
+        Self {
+            ip_address: AssignIPAddress {
+                family: Some(IP_ADDRESS_FAMILY_IPV4.to_string()),
+                floating: Some(true),
+                zone: Some(zone.into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_access(mut self, access: impl Into<String>) -> Self {
+        self.ip_address.access = Some(access.into());
+        self
+    }
+
+    pub fn with_family(mut self, family: impl Into<String>) -> Self {
+        self.ip_address.family = Some(family.into());
+        self
+    }
+
+    pub fn with_mac(mut self, mac: impl Into<String>) -> Self {
+        self.ip_address.mac = Some(mac.into());
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyIPAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ptr_record: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyIPAddressRequest {
+    pub ip_address: ModifyIPAddress,
+}
+
+impl ModifyIPAddressRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_ptr_record(mut self, ptr_record: impl Into<String>) -> Self {
+        self.ip_address.ptr_record = Some(ptr_record.into());
+        self
+    }
+
+    /// Attach a floating IP address to the network interface with the given
+    /// MAC address. An empty string detaches it.
+    pub fn with_mac(mut self, mac: impl Into<String>) -> Self {
+        self.ip_address.mac = Some(mac.into());
+        self
+    }
+}
@@ -0,0 +1,337 @@
+use serde::{Deserialize, Serialize};
+use crate::types::common::Label;
+
+pub const KUBERNETES_CLUSTER_STATE_RUNNING: &str = "running";
+pub const KUBERNETES_CLUSTER_STATE_PENDING: &str = "pending";
+pub const KUBERNETES_CLUSTER_STATE_TERMINATING: &str = "terminating";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KubernetesTaintEffect {
+    NoSchedule,
+    PreferNoSchedule,
+    NoExecute,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubernetesCluster {
+    pub uuid: String,
+    pub name: String,
+    pub network: String,
+    #[serde(default)]
+    pub network_cidr: String,
+    pub zone: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub plan: String,
+    pub state: String,
+    #[serde(default)]
+    pub control_plane_ip_filter: Vec<String>,
+    #[serde(default)]
+    pub private_node_groups: bool,
+    #[serde(default)]
+    pub node_groups: Vec<KubernetesNodeGroup>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KubernetesTaint {
+    pub key: String,
+    #[serde(default)]
+    pub value: String,
+    pub effect: Option<KubernetesTaintEffect>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KubernetesKubeletArg {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubernetesNodeGroup {
+    pub name: String,
+    pub count: i32,
+    pub plan: String,
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub taints: Vec<KubernetesTaint>,
+    #[serde(default)]
+    pub kubelet_args: Vec<KubernetesKubeletArg>,
+    #[serde(default)]
+    pub ssh_keys: Vec<String>,
+    #[serde(default)]
+    pub anti_affinity: bool,
+    #[serde(default)]
+    pub utility_network_access: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateKubernetesNodeGroupRequest {
+    pub name: String,
+    pub count: i32,
+    pub plan: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<Label>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub taints: Vec<KubernetesTaint>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub kubelet_args: Vec<KubernetesKubeletArg>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ssh_keys: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anti_affinity: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utility_network_access: Option<bool>,
+}
+
+impl CreateKubernetesNodeGroupRequest {
+    pub fn new(name: impl Into<String>, plan: impl Into<String>, count: i32) -> Self {
+        Self {
+            name: name.into(),
+            plan: plan.into(),
+            count,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_label(mut self, key: &str, value: &str) -> Self {
+        self.labels.push(Label { key: key.to_string(), value: value.to_string() });
+        self
+    }
+
+    pub fn with_taint(mut self, key: impl Into<String>, value: impl Into<String>, effect: KubernetesTaintEffect) -> Self {
+        self.taints.push(KubernetesTaint { key: key.into(), value: value.into(), effect: Some(effect) });
+        self
+    }
+
+    pub fn with_kubelet_arg(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.kubelet_args.push(KubernetesKubeletArg { key: key.into(), value: value.into() });
+        self
+    }
+
+    pub fn with_ssh_key(mut self, key: impl Into<String>) -> Self {
+        self.ssh_keys.push(key.into());
+        self
+    }
+
+    pub fn with_anti_affinity(mut self, anti_affinity: bool) -> Self {
+        self.anti_affinity = Some(anti_affinity);
+        self
+    }
+
+    pub fn with_utility_network_access(mut self, enabled: bool) -> Self {
+        self.utility_network_access = Some(enabled);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModifyKubernetesNodeGroupRequest {
+    pub count: i32,
+}
+
+impl ModifyKubernetesNodeGroupRequest {
+    pub fn new(count: i32) -> Self {
+        Self { count }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateKubernetesClusterRequest {
+    pub name: String,
+    pub network: String,
+    pub zone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_cidr: Option<String>,
+    pub control_plane_ip_filter: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_node_groups: Option<bool>,
+    pub node_groups: Vec<CreateKubernetesNodeGroupRequest>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<Label>,
+}
+
+impl CreateKubernetesClusterRequest {
+    /// Creates a cluster attached to an existing private network.
+    pub fn new(name: impl Into<String>, network: impl Into<String>, zone: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            network: network.into(),
+            zone: zone.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn with_plan(mut self, plan: impl Into<String>) -> Self {
+        self.plan = Some(plan.into());
+        self
+    }
+
+    pub fn with_network_cidr(mut self, network_cidr: impl Into<String>) -> Self {
+        self.network_cidr = Some(network_cidr.into());
+        self
+    }
+
+    /// Allows access to the Kubernetes API from the given CIDR block.
+    /// Use `0.0.0.0/0` to allow access from anywhere.
+    pub fn with_control_plane_ip_filter(mut self, cidr: impl Into<String>) -> Self {
+        self.control_plane_ip_filter.push(cidr.into());
+        self
+    }
+
+    pub fn with_private_node_groups(mut self, private: bool) -> Self {
+        self.private_node_groups = Some(private);
+        self
+    }
+
+    pub fn with_node_group(mut self, node_group: CreateKubernetesNodeGroupRequest) -> Self {
+        self.node_groups.push(node_group);
+        self
+    }
+
+    pub fn with_label(mut self, key: &str, value: &str) -> Self {
+        self.labels.push(Label { key: key.to_string(), value: value.to_string() });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyKubernetesClusterRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control_plane_ip_filter: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<Label>>,
+}
+
+impl ModifyKubernetesClusterRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the control plane IP filter.
+    pub fn with_control_plane_ip_filter(mut self, cidr: impl Into<String>) -> Self {
+        self.control_plane_ip_filter.get_or_insert_with(Vec::new).push(cidr.into());
+        self
+    }
+
+    pub fn with_label(mut self, key: &str, value: &str) -> Self {
+        self.labels.get_or_insert_with(Vec::new).push(Label { key: key.to_string(), value: value.to_string() });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubernetesPlan {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubernetesVersion {
+    pub id: String,
+    pub version: String,
+}
+
+#[derive(Deserialize)]
+pub struct GetKubeconfigResponse {
+    pub kubeconfig: String,
+}
+
+// The raw kubeconfig embeds the client key.
+impl std::fmt::Debug for GetKubeconfigResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GetKubeconfigResponse")
+            .field("kubeconfig", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Parsed kubeconfig, returned by `get_kubernetes_kubeconfig` when the
+/// `kubeconfig` feature is enabled. Only the fields needed to reach the
+/// cluster are modelled; use the raw YAML for anything else.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Kubeconfig {
+    #[serde(rename = "apiVersion", default)]
+    pub api_version: String,
+    #[serde(default)]
+    pub kind: String,
+    #[serde(default)]
+    pub clusters: Vec<KubeconfigNamedCluster>,
+    #[serde(default)]
+    pub users: Vec<KubeconfigNamedUser>,
+    #[serde(default)]
+    pub contexts: Vec<KubeconfigNamedContext>,
+    #[serde(default)]
+    pub current_context: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubeconfigNamedCluster {
+    pub name: String,
+    pub cluster: KubeconfigCluster,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct KubeconfigCluster {
+    pub server: String,
+    #[serde(default)]
+    pub certificate_authority_data: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubeconfigNamedUser {
+    pub name: String,
+    pub user: KubeconfigUser,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct KubeconfigUser {
+    #[serde(default)]
+    pub client_certificate_data: Option<String>,
+    #[serde(default)]
+    pub client_key_data: Option<String>,
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+impl std::fmt::Debug for KubeconfigUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KubeconfigUser")
+            .field("client_certificate_data", &self.client_certificate_data)
+            .field("client_key_data", &self.client_key_data.as_ref().map(|_| "<redacted>"))
+            .field("token", &self.token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubeconfigNamedContext {
+    pub name: String,
+    pub context: KubeconfigContext,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubeconfigContext {
+    pub cluster: String,
+    pub user: String,
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
@@ -0,0 +1,1256 @@
+use serde::{Deserialize, Serialize};
+use crate::types::common::Label;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadBalancerConfiguredStatus {
+    #[default]
+    Started,
+    Stopped,
+}
+
+impl LoadBalancerConfiguredStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoadBalancerConfiguredStatus::Started => "started",
+            LoadBalancerConfiguredStatus::Stopped => "stopped",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadBalancerNetworkType {
+    Public,
+    Private,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadBalancerMode {
+    Http,
+    Tcp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadBalancerHealthCheckType {
+    Tcp,
+    Http,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadBalancerMemberType {
+    Static,
+    Dynamic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadBalancerMatchingCondition {
+    And,
+    Or,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadBalancerCertificateBundleType {
+    Manual,
+    Dynamic,
+    Authority,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StringMatcherMethod {
+    Exact,
+    Substring,
+    Regexp,
+    Starts,
+    Ends,
+    Domain,
+    Ip,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegerMatcherMethod {
+    Equal,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LoadBalancer {
+    pub uuid: String,
+    pub name: String,
+    pub zone: String,
+    pub plan: String,
+    #[serde(default)]
+    pub dns_name: String,
+    pub configured_status: LoadBalancerConfiguredStatus,
+    #[serde(default)]
+    pub operational_state: String,
+    #[serde(default)]
+    pub networks: Vec<LoadBalancerNetworkDetails>,
+    #[serde(default)]
+    pub frontends: Vec<LoadBalancerFrontend>,
+    #[serde(default)]
+    pub backends: Vec<LoadBalancerBackend>,
+    #[serde(default)]
+    pub resolvers: Vec<LoadBalancerResolver>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LoadBalancerNetworkDetails {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub network_type: String,
+    #[serde(default)]
+    pub family: String,
+    #[serde(default)]
+    pub uuid: Option<String>,
+    #[serde(default)]
+    pub dns_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadBalancerNetwork {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub network_type: LoadBalancerNetworkType,
+    pub family: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+}
+
+impl LoadBalancerNetwork {
+    pub fn public(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            network_type: LoadBalancerNetworkType::Public,
+            family: "IPv4".to_string(),
+            uuid: None,
+        }
+    }
+
+    pub fn private(name: impl Into<String>, network_uuid: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            network_type: LoadBalancerNetworkType::Private,
+            family: "IPv4".to_string(),
+            uuid: Some(network_uuid.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateLoadBalancerRequest {
+    pub name: String,
+    pub plan: String,
+    pub zone: String,
+    pub configured_status: LoadBalancerConfiguredStatus,
+    pub networks: Vec<LoadBalancerNetwork>,
+    pub frontends: Vec<CreateFrontendRequest>,
+    pub backends: Vec<CreateBackendRequest>,
+    pub resolvers: Vec<CreateResolverRequest>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<Label>,
+}
+
+impl CreateLoadBalancerRequest {
+    pub fn new(name: impl Into<String>, plan: impl Into<String>, zone: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            plan: plan.into(),
+            zone: zone.into(),
+            configured_status: LoadBalancerConfiguredStatus::Started,
+            networks: Vec::new(),
+            frontends: Vec::new(),
+            backends: Vec::new(),
+            resolvers: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    pub fn with_configured_status(mut self, status: LoadBalancerConfiguredStatus) -> Self {
+        self.configured_status = status;
+        self
+    }
+
+    pub fn with_network(mut self, network: LoadBalancerNetwork) -> Self {
+        self.networks.push(network);
+        self
+    }
+
+    pub fn with_frontend(mut self, frontend: CreateFrontendRequest) -> Self {
+        self.frontends.push(frontend);
+        self
+    }
+
+    pub fn with_backend(mut self, backend: CreateBackendRequest) -> Self {
+        self.backends.push(backend);
+        self
+    }
+
+    pub fn with_resolver(mut self, resolver: CreateResolverRequest) -> Self {
+        self.resolvers.push(resolver);
+        self
+    }
+
+    pub fn with_label(mut self, key: &str, value: &str) -> Self {
+        self.labels.push(Label { key: key.to_string(), value: value.to_string() });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyLoadBalancerRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configured_status: Option<LoadBalancerConfiguredStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<Label>>,
+}
+
+impl ModifyLoadBalancerRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_plan(mut self, plan: impl Into<String>) -> Self {
+        self.plan = Some(plan.into());
+        self
+    }
+
+    pub fn with_configured_status(mut self, status: LoadBalancerConfiguredStatus) -> Self {
+        self.configured_status = Some(status);
+        self
+    }
+
+    pub fn with_label(mut self, key: &str, value: &str) -> Self {
+        self.labels.get_or_insert_with(Vec::new).push(Label { key: key.to_string(), value: value.to_string() });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoadBalancerFrontendProperties {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_client: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_proxy_protocol: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http2_enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadBalancerFrontendNetwork {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoadBalancerFrontend {
+    pub name: String,
+    pub mode: LoadBalancerMode,
+    pub port: i32,
+    pub default_backend: String,
+    #[serde(default)]
+    pub networks: Vec<LoadBalancerFrontendNetwork>,
+    #[serde(default)]
+    pub rules: Vec<LoadBalancerRule>,
+    #[serde(default)]
+    pub tls_configs: Vec<LoadBalancerFrontendTLSConfig>,
+    #[serde(default)]
+    pub properties: Option<LoadBalancerFrontendProperties>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateFrontendRequest {
+    pub name: String,
+    pub mode: LoadBalancerMode,
+    pub port: i32,
+    pub default_backend: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub networks: Vec<LoadBalancerFrontendNetwork>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<CreateRuleRequest>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tls_configs: Vec<CreateFrontendTLSConfigRequest>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<LoadBalancerFrontendProperties>,
+}
+
+impl CreateFrontendRequest {
+    pub fn new(
+        name: impl Into<String>,
+        mode: LoadBalancerMode,
+        port: i32,
+        default_backend: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            mode,
+            port,
+            default_backend: default_backend.into(),
+            networks: Vec::new(),
+            rules: Vec::new(),
+            tls_configs: Vec::new(),
+            properties: None,
+        }
+    }
+
+    /// Listens on the load balancer network with the given name.
+    pub fn with_network(mut self, name: impl Into<String>) -> Self {
+        self.networks.push(LoadBalancerFrontendNetwork { name: name.into() });
+        self
+    }
+
+    pub fn with_rule(mut self, rule: CreateRuleRequest) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn with_tls_config(mut self, tls_config: CreateFrontendTLSConfigRequest) -> Self {
+        self.tls_configs.push(tls_config);
+        self
+    }
+
+    pub fn with_properties(mut self, properties: LoadBalancerFrontendProperties) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyFrontendRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LoadBalancerMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_backend: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<LoadBalancerFrontendProperties>,
+}
+
+impl ModifyFrontendRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_mode(mut self, mode: LoadBalancerMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn with_port(mut self, port: i32) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    pub fn with_default_backend(mut self, default_backend: impl Into<String>) -> Self {
+        self.default_backend = Some(default_backend.into());
+        self
+    }
+
+    pub fn with_properties(mut self, properties: LoadBalancerFrontendProperties) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoadBalancerBackendProperties {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_server: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_tunnel: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_type: Option<LoadBalancerHealthCheckType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_interval: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_fall: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_rise: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_expected_status: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticky_session_cookie_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_proxy_protocol: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoadBalancerBackend {
+    pub name: String,
+    #[serde(default)]
+    pub resolver: Option<String>,
+    #[serde(default)]
+    pub members: Vec<LoadBalancerBackendMember>,
+    #[serde(default)]
+    pub properties: Option<LoadBalancerBackendProperties>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateBackendRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolver: Option<String>,
+    pub members: Vec<CreateBackendMemberRequest>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<LoadBalancerBackendProperties>,
+}
+
+impl CreateBackendRequest {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            resolver: None,
+            members: Vec::new(),
+            properties: None,
+        }
+    }
+
+    pub fn with_resolver(mut self, resolver: impl Into<String>) -> Self {
+        self.resolver = Some(resolver.into());
+        self
+    }
+
+    pub fn with_member(mut self, member: CreateBackendMemberRequest) -> Self {
+        self.members.push(member);
+        self
+    }
+
+    pub fn with_properties(mut self, properties: LoadBalancerBackendProperties) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyBackendRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolver: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<LoadBalancerBackendProperties>,
+}
+
+impl ModifyBackendRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_resolver(mut self, resolver: impl Into<String>) -> Self {
+        self.resolver = Some(resolver.into());
+        self
+    }
+
+    pub fn with_properties(mut self, properties: LoadBalancerBackendProperties) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoadBalancerBackendMember {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub member_type: LoadBalancerMemberType,
+    #[serde(default)]
+    pub ip: Option<String>,
+    #[serde(default)]
+    pub port: Option<i32>,
+    #[serde(default)]
+    pub weight: i32,
+    #[serde(default)]
+    pub max_sessions: i32,
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub backup: bool,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateBackendMemberRequest {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub member_type: LoadBalancerMemberType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i32>,
+    pub weight: i32,
+    pub max_sessions: i32,
+    pub enabled: bool,
+    pub backup: bool,
+}
+
+impl CreateBackendMemberRequest {
+    /// A member with a fixed IP address and port.
+    pub fn new_static(name: impl Into<String>, ip: impl Into<String>, port: i32) -> Self {
+        Self {
+            name: name.into(),
+            member_type: LoadBalancerMemberType::Static,
+            ip: Some(ip.into()),
+            port: Some(port),
+            weight: 100,
+            max_sessions: 1000,
+            enabled: true,
+            backup: false,
+        }
+    }
+
+    /// A member whose address is resolved through the backend's resolver.
+    pub fn new_dynamic(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            member_type: LoadBalancerMemberType::Dynamic,
+            ip: None,
+            port: None,
+            weight: 100,
+            max_sessions: 1000,
+            enabled: true,
+            backup: false,
+        }
+    }
+
+    pub fn with_weight(mut self, weight: i32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn with_max_sessions(mut self, max_sessions: i32) -> Self {
+        self.max_sessions = max_sessions;
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn with_backup(mut self, backup: bool) -> Self {
+        self.backup = backup;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyBackendMemberRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_sessions: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup: Option<bool>,
+}
+
+impl ModifyBackendMemberRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_address(mut self, ip: impl Into<String>, port: i32) -> Self {
+        self.ip = Some(ip.into());
+        self.port = Some(port);
+        self
+    }
+
+    pub fn with_weight(mut self, weight: i32) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    pub fn with_max_sessions(mut self, max_sessions: i32) -> Self {
+        self.max_sessions = Some(max_sessions);
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    pub fn with_backup(mut self, backup: bool) -> Self {
+        self.backup = Some(backup);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoadBalancerResolver {
+    pub name: String,
+    #[serde(default)]
+    pub nameservers: Vec<String>,
+    #[serde(default)]
+    pub retries: i32,
+    #[serde(default)]
+    pub timeout: i32,
+    #[serde(default)]
+    pub timeout_retry: i32,
+    #[serde(default)]
+    pub cache_valid: i32,
+    #[serde(default)]
+    pub cache_invalid: i32,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateResolverRequest {
+    pub name: String,
+    pub nameservers: Vec<String>,
+    pub retries: i32,
+    pub timeout: i32,
+    pub timeout_retry: i32,
+    pub cache_valid: i32,
+    pub cache_invalid: i32,
+}
+
+impl CreateResolverRequest {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            nameservers: Vec::new(),
+            retries: 5,
+            timeout: 30,
+            timeout_retry: 10,
+            cache_valid: 180,
+            cache_invalid: 10,
+        }
+    }
+
+    /// Adds a nameserver as `address:port`, e.g. `10.0.0.10:53`.
+    pub fn with_nameserver(mut self, nameserver: impl Into<String>) -> Self {
+        self.nameservers.push(nameserver.into());
+        self
+    }
+
+    pub fn with_retries(mut self, retries: i32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    pub fn with_timeouts(mut self, timeout: i32, timeout_retry: i32) -> Self {
+        self.timeout = timeout;
+        self.timeout_retry = timeout_retry;
+        self
+    }
+
+    pub fn with_cache(mut self, cache_valid: i32, cache_invalid: i32) -> Self {
+        self.cache_valid = cache_valid;
+        self.cache_invalid = cache_invalid;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyResolverRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nameservers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_retry: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_valid: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_invalid: Option<i32>,
+}
+
+impl ModifyResolverRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Replaces the nameservers of the resolver.
+    pub fn with_nameserver(mut self, nameserver: impl Into<String>) -> Self {
+        self.nameservers.get_or_insert_with(Vec::new).push(nameserver.into());
+        self
+    }
+
+    pub fn with_retries(mut self, retries: i32) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    pub fn with_timeouts(mut self, timeout: i32, timeout_retry: i32) -> Self {
+        self.timeout = Some(timeout);
+        self.timeout_retry = Some(timeout_retry);
+        self
+    }
+
+    pub fn with_cache(mut self, cache_valid: i32, cache_invalid: i32) -> Self {
+        self.cache_valid = Some(cache_valid);
+        self.cache_invalid = Some(cache_invalid);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StringMatcher {
+    pub method: StringMatcherMethod,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_case: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamedStringMatcher {
+    pub method: StringMatcherMethod,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_case: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegerMatcher {
+    pub method: IntegerMatcherMethod,
+    pub value: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RangeMatcher {
+    pub range_start: i64,
+    pub range_end: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValueMatcher {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumMembersUpMatcher {
+    pub method: IntegerMatcherMethod,
+    pub value: i64,
+    pub backend: String,
+}
+
+/// Condition of a frontend rule. Each variant carries the matcher-specific
+/// payload the API expects under `match_<type>`.
+///
+/// Matcher types not known to this SDK are kept as [`RuleMatcherKind::Unknown`]
+/// with their raw payload, so rules read from the API can be sent back as is.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleMatcherKind {
+    SrcIp { match_src_ip: ValueMatcher },
+    SrcPort { match_src_port: IntegerMatcher },
+    SrcPortRange { match_src_port_range: RangeMatcher },
+    BodySize { match_body_size: IntegerMatcher },
+    BodySizeRange { match_body_size_range: RangeMatcher },
+    Path { match_path: StringMatcher },
+    Url { match_url: StringMatcher },
+    UrlQuery { match_url_query: StringMatcher },
+    Host { match_host: ValueMatcher },
+    HttpMethod { match_http_method: ValueMatcher },
+    Cookie { match_cookie: NamedStringMatcher },
+    Header { match_header: NamedStringMatcher },
+    RequestHeader { match_request_header: NamedStringMatcher },
+    ResponseHeader { match_response_header: NamedStringMatcher },
+    UrlParam { match_url_param: NamedStringMatcher },
+    HttpStatus { match_http_status: IntegerMatcher },
+    HttpStatusRange { match_http_status_range: RangeMatcher },
+    NumMembersUp { match_num_members_up: NumMembersUpMatcher },
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleMatcher {
+    #[serde(flatten)]
+    pub kind: RuleMatcherKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse: Option<bool>,
+}
+
+impl RuleMatcher {
+    pub fn new(kind: RuleMatcherKind) -> Self {
+        Self { kind, inverse: None }
+    }
+
+    pub fn src_ip(value: impl Into<String>) -> Self {
+        Self::new(RuleMatcherKind::SrcIp { match_src_ip: ValueMatcher { value: value.into() } })
+    }
+
+    pub fn host(value: impl Into<String>) -> Self {
+        Self::new(RuleMatcherKind::Host { match_host: ValueMatcher { value: value.into() } })
+    }
+
+    pub fn http_method(value: impl Into<String>) -> Self {
+        Self::new(RuleMatcherKind::HttpMethod { match_http_method: ValueMatcher { value: value.into() } })
+    }
+
+    pub fn path(method: StringMatcherMethod, value: impl Into<String>) -> Self {
+        Self::new(RuleMatcherKind::Path {
+            match_path: StringMatcher { method, value: Some(value.into()), ignore_case: None },
+        })
+    }
+
+    pub fn header(method: StringMatcherMethod, name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::new(RuleMatcherKind::Header {
+            match_header: NamedStringMatcher {
+                method,
+                name: name.into(),
+                value: Some(value.into()),
+                ignore_case: None,
+            },
+        })
+    }
+
+    pub fn request_header(method: StringMatcherMethod, name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::new(RuleMatcherKind::RequestHeader {
+            match_request_header: NamedStringMatcher {
+                method,
+                name: name.into(),
+                value: Some(value.into()),
+                ignore_case: None,
+            },
+        })
+    }
+
+    pub fn response_header(method: StringMatcherMethod, name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::new(RuleMatcherKind::ResponseHeader {
+            match_response_header: NamedStringMatcher {
+                method,
+                name: name.into(),
+                value: Some(value.into()),
+                ignore_case: None,
+            },
+        })
+    }
+
+    pub fn http_status(method: IntegerMatcherMethod, value: i64) -> Self {
+        Self::new(RuleMatcherKind::HttpStatus { match_http_status: IntegerMatcher { method, value } })
+    }
+
+    pub fn http_status_range(range_start: i64, range_end: i64) -> Self {
+        Self::new(RuleMatcherKind::HttpStatusRange {
+            match_http_status_range: RangeMatcher { range_start, range_end },
+        })
+    }
+
+    pub fn num_members_up(method: IntegerMatcherMethod, value: i64, backend: impl Into<String>) -> Self {
+        Self::new(RuleMatcherKind::NumMembersUp {
+            match_num_members_up: NumMembersUpMatcher { method, value, backend: backend.into() },
+        })
+    }
+
+    pub fn inverse(mut self) -> Self {
+        self.inverse = Some(true);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UseBackendAction {
+    pub backend: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpReturnAction {
+    pub status: i32,
+    pub content_type: String,
+    /// Base64 encoded response body.
+    pub payload: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpRedirectAction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetHeaderAction {
+    pub header: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmptyAction {}
+
+/// Action of a frontend rule, serialized with its payload under
+/// `action_<type>`.
+///
+/// Action types not known to this SDK are kept as [`RuleAction::Unknown`]
+/// with their raw payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleAction {
+    UseBackend { action_use_backend: UseBackendAction },
+    TcpReject {
+        #[serde(default)]
+        action_tcp_reject: EmptyAction,
+    },
+    HttpReturn { action_http_return: HttpReturnAction },
+    HttpRedirect { action_http_redirect: HttpRedirectAction },
+    SetForwardedHeaders {
+        #[serde(default)]
+        action_set_forwarded_headers: EmptyAction,
+    },
+    SetRequestHeader { action_set_request_header: SetHeaderAction },
+    SetResponseHeader { action_set_response_header: SetHeaderAction },
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl RuleAction {
+    pub fn use_backend(backend: impl Into<String>) -> Self {
+        Self::UseBackend { action_use_backend: UseBackendAction { backend: backend.into() } }
+    }
+
+    pub fn tcp_reject() -> Self {
+        Self::TcpReject { action_tcp_reject: EmptyAction {} }
+    }
+
+    pub fn http_redirect_location(location: impl Into<String>) -> Self {
+        Self::HttpRedirect {
+            action_http_redirect: HttpRedirectAction { location: Some(location.into()), scheme: None },
+        }
+    }
+
+    pub fn http_redirect_scheme(scheme: impl Into<String>) -> Self {
+        Self::HttpRedirect {
+            action_http_redirect: HttpRedirectAction { location: None, scheme: Some(scheme.into()) },
+        }
+    }
+
+    pub fn set_forwarded_headers() -> Self {
+        Self::SetForwardedHeaders { action_set_forwarded_headers: EmptyAction {} }
+    }
+
+    /// Sets a request header before passing the request to the backend. A
+    /// header without a value is removed.
+    pub fn set_request_header(header: impl Into<String>, value: Option<String>) -> Self {
+        Self::SetRequestHeader {
+            action_set_request_header: SetHeaderAction { header: header.into(), value },
+        }
+    }
+
+    /// Sets a response header before returning the response to the client.
+    /// A header without a value is removed.
+    pub fn set_response_header(header: impl Into<String>, value: Option<String>) -> Self {
+        Self::SetResponseHeader {
+            action_set_response_header: SetHeaderAction { header: header.into(), value },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoadBalancerRule {
+    pub name: String,
+    pub priority: i32,
+    #[serde(default)]
+    pub matching_condition: Option<LoadBalancerMatchingCondition>,
+    #[serde(default)]
+    pub matchers: Vec<RuleMatcher>,
+    #[serde(default)]
+    pub actions: Vec<RuleAction>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateRuleRequest {
+    pub name: String,
+    pub priority: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matching_condition: Option<LoadBalancerMatchingCondition>,
+    pub matchers: Vec<RuleMatcher>,
+    pub actions: Vec<RuleAction>,
+}
+
+impl CreateRuleRequest {
+    pub fn new(name: impl Into<String>, priority: i32) -> Self {
+        Self {
+            name: name.into(),
+            priority,
+            matching_condition: None,
+            matchers: Vec::new(),
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn with_matching_condition(mut self, condition: LoadBalancerMatchingCondition) -> Self {
+        self.matching_condition = Some(condition);
+        self
+    }
+
+    pub fn with_matcher(mut self, matcher: RuleMatcher) -> Self {
+        self.matchers.push(matcher);
+        self
+    }
+
+    pub fn with_action(mut self, action: RuleAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyRuleRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matching_condition: Option<LoadBalancerMatchingCondition>,
+}
+
+impl ModifyRuleRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    pub fn with_matching_condition(mut self, condition: LoadBalancerMatchingCondition) -> Self {
+        self.matching_condition = Some(condition);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoadBalancerFrontendTLSConfig {
+    pub name: String,
+    pub certificate_bundle_uuid: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateFrontendTLSConfigRequest {
+    pub name: String,
+    pub certificate_bundle_uuid: String,
+}
+
+impl CreateFrontendTLSConfigRequest {
+    pub fn new(name: impl Into<String>, certificate_bundle_uuid: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            certificate_bundle_uuid: certificate_bundle_uuid.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoadBalancerCertificateBundle {
+    pub uuid: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub bundle_type: LoadBalancerCertificateBundleType,
+    #[serde(default)]
+    pub certificate: Option<String>,
+    #[serde(default)]
+    pub intermediates: Option<String>,
+    #[serde(default)]
+    pub hostnames: Vec<String>,
+    #[serde(default)]
+    pub key_type: Option<String>,
+    #[serde(default)]
+    pub not_after: Option<String>,
+    #[serde(default)]
+    pub not_before: Option<String>,
+    #[serde(default)]
+    pub operational_state: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateCertificateBundleRequest {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub bundle_type: LoadBalancerCertificateBundleType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intermediates: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hostnames: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<String>,
+}
+
+impl CreateCertificateBundleRequest {
+    /// A bundle from a base64 encoded PEM certificate and private key.
+    pub fn manual(
+        name: impl Into<String>,
+        certificate: impl Into<String>,
+        private_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            bundle_type: LoadBalancerCertificateBundleType::Manual,
+            certificate: Some(certificate.into()),
+            intermediates: None,
+            private_key: Some(private_key.into()),
+            hostnames: Vec::new(),
+            key_type: None,
+        }
+    }
+
+    /// A bundle with certificates issued and renewed automatically for the
+    /// given hostnames.
+    pub fn dynamic(name: impl Into<String>, key_type: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            bundle_type: LoadBalancerCertificateBundleType::Dynamic,
+            certificate: None,
+            intermediates: None,
+            private_key: None,
+            hostnames: Vec::new(),
+            key_type: Some(key_type.into()),
+        }
+    }
+
+    pub fn with_intermediates(mut self, intermediates: impl Into<String>) -> Self {
+        self.intermediates = Some(intermediates.into());
+        self
+    }
+
+    pub fn with_hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostnames.push(hostname.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyCertificateBundleRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intermediates: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostnames: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<String>,
+}
+
+impl ModifyCertificateBundleRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Replaces the certificate and private key of a manual bundle.
+    pub fn with_certificate(mut self, certificate: impl Into<String>, private_key: impl Into<String>) -> Self {
+        self.certificate = Some(certificate.into());
+        self.private_key = Some(private_key.into());
+        self
+    }
+
+    pub fn with_intermediates(mut self, intermediates: impl Into<String>) -> Self {
+        self.intermediates = Some(intermediates.into());
+        self
+    }
+
+    /// Replaces the hostnames of a dynamic bundle.
+    pub fn with_hostnames(mut self, hostnames: Vec<String>) -> Self {
+        self.hostnames = Some(hostnames);
+        self
+    }
+
+    pub fn with_key_type(mut self, key_type: impl Into<String>) -> Self {
+        self.key_type = Some(key_type.into());
+        self
+    }
+}
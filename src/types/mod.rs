@@ -1,2 +1,17 @@
 pub mod server;
-pub mod common;
\ No newline at end of file
+pub mod common;
+pub mod storage;
+pub mod ip_address;
+pub mod firewall;
+pub mod network;
+pub mod router;
+pub mod load_balancer;
+pub mod database;
+pub mod kubernetes;
+pub mod object_storage;
+pub mod account;
+pub mod catalog;
+pub mod server_group;
+pub mod tag;
+pub mod template;
+pub mod token;
\ No newline at end of file
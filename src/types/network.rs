@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+use crate::types::common::{Labels, deserialize_empty_as_none};
+use crate::types::server::{
+    CreateServerInterface,
+    NetworkInterface,
+    ServerNetworking,
+    deserialize_yes_no_as_option_bool,
+    serialize_bool_as_yes_no,
+};
+
+pub const NETWORK_TYPE_PUBLIC: &str = "public";
+pub const NETWORK_TYPE_UTILITY: &str = "utility";
+pub const NETWORK_TYPE_PRIVATE: &str = "private";
+
+#[derive(Debug, Deserialize)]
+pub struct GetNetworksResponse {
+    pub networks: NetworkList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetNetworkDetailsResponse {
+    pub network: Network,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetServerNetworkingResponse {
+    pub networking: ServerNetworking,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ServerInterfaceResponse {
+    pub interface: NetworkInterface,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateServerInterfaceRequestWrapper {
+    pub interface: CreateServerInterface,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModifyServerInterfaceRequestWrapper {
+    pub interface: NetworkInterface,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NetworkList {
+    #[serde(default)]
+    pub network: Vec<Network>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Network {
+    #[serde(default)]
+    pub ip_networks: IpNetworkList,
+    #[serde(default)]
+    pub labels: Option<Labels>,
+    pub name: String,
+    #[serde(default, deserialize_with = "deserialize_empty_as_none")]
+    pub router: Option<String>,
+    #[serde(default)]
+    pub servers: NetworkServerList,
+    #[serde(rename = "type")]
+    pub network_type: String,
+    pub uuid: String,
+    pub zone: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NetworkServerList {
+    #[serde(default)]
+    pub server: Vec<NetworkServer>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NetworkServer {
+    pub uuid: String,
+    #[serde(default)]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IpNetworkList {
+    #[serde(default)]
+    pub ip_network: Vec<IpNetwork>,
+}
+
+/// An IP network (subnet) of an SDN network.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IpNetwork {
+    pub address: String,
+    pub family: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_bool_as_yes_no",
+        deserialize_with = "deserialize_yes_no_as_option_bool"
+    )]
+    pub dhcp: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_bool_as_yes_no",
+        deserialize_with = "deserialize_yes_no_as_option_bool"
+    )]
+    pub dhcp_default_route: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub dhcp_dns: Option<DhcpDns>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub dhcp_routes: Option<DhcpRoutes>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub gateway: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DhcpDns {
+    #[serde(default)]
+    pub dhcp_dns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DhcpRoutes {
+    #[serde(default)]
+    pub dhcp_route: Vec<String>,
+}
+
+impl IpNetwork {
+    pub fn new(address: impl Into<String>, family: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            family: family.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_dhcp(mut self, enabled: bool) -> Self {
+        self.dhcp = Some(enabled);
+        self
+    }
+
+    pub fn with_dhcp_default_route(mut self, enabled: bool) -> Self {
+        self.dhcp_default_route = Some(enabled);
+        self
+    }
+
+    pub fn with_dhcp_dns(mut self, dns: impl Into<String>) -> Self {
+        self.dhcp_dns.get_or_insert_with(Default::default).dhcp_dns.push(dns.into());
+        self
+    }
+
+    /// Adds a route pushed to DHCP clients in CIDR notation, e.g. `10.0.0.0/8`.
+    pub fn with_dhcp_route(mut self, route: impl Into<String>) -> Self {
+        self.dhcp_routes.get_or_insert_with(Default::default).dhcp_route.push(route.into());
+        self
+    }
+
+    pub fn with_gateway(mut self, gateway: impl Into<String>) -> Self {
+        self.gateway = Some(gateway.into());
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct NetworkRequest {
+    pub name: String,
+    pub zone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub router: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Labels>,
+    pub ip_networks: IpNetworkList,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateNetworkRequest {
+    pub network: NetworkRequest,
+}
+
+impl CreateNetworkRequest {
+    pub fn new(name: impl Into<String>, zone: impl Into<String>) -> Self {
+        Self {
+            network: NetworkRequest {
+                name: name.into(),
+                zone: zone.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_ip_network(mut self, ip_network: IpNetwork) -> Self {
+        self.network.ip_networks.ip_network.push(ip_network);
+        self
+    }
+
+    pub fn with_router(mut self, router: impl Into<String>) -> Self {
+        self.network.router = Some(router.into());
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.network.labels = Some(labels);
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyNetwork {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_networks: Option<IpNetworkList>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Labels>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyNetworkRequest {
+    pub network: ModifyNetwork,
+}
+
+impl ModifyNetworkRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.network.name = Some(name.into());
+        self
+    }
+
+    pub fn with_ip_network(mut self, ip_network: IpNetwork) -> Self {
+        self.network.ip_networks.get_or_insert_with(Default::default).ip_network.push(ip_network);
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.network.labels = Some(labels);
+        self
+    }
+}
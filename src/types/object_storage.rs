@@ -0,0 +1,318 @@
+use serde::{Deserialize, Serialize};
+use crate::types::common::{Labels, labels_as_list};
+
+pub const OBJECT_STORAGE_NETWORK_TYPE_PUBLIC: &str = "public";
+pub const OBJECT_STORAGE_NETWORK_TYPE_PRIVATE: &str = "private";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ObjectStorageConfiguredStatus {
+    Started,
+    Stopped,
+}
+
+impl ObjectStorageConfiguredStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ObjectStorageConfiguredStatus::Started => "started",
+            ObjectStorageConfiguredStatus::Stopped => "stopped",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccessKeyStatus {
+    Active,
+    Inactive,
+}
+
+impl AccessKeyStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccessKeyStatus::Active => "Active",
+            AccessKeyStatus::Inactive => "Inactive",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectStorage {
+    pub uuid: String,
+    pub name: String,
+    pub region: String,
+    pub configured_status: ObjectStorageConfiguredStatus,
+    #[serde(default)]
+    pub operational_state: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+    #[serde(default)]
+    pub endpoints: Vec<ObjectStorageEndpoint>,
+    #[serde(default)]
+    pub networks: Vec<ObjectStorageNetwork>,
+    #[serde(default, with = "labels_as_list")]
+    pub labels: Option<Labels>,
+    #[serde(default)]
+    pub users: Vec<ObjectStorageUser>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectStorageEndpoint {
+    pub domain_name: String,
+    #[serde(rename = "type")]
+    pub endpoint_type: String,
+    #[serde(default)]
+    pub iam_url: Option<String>,
+    #[serde(default)]
+    pub sts_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectStorageNetwork {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub network_type: String,
+    pub family: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+}
+
+impl ObjectStorageNetwork {
+    pub fn public(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            network_type: OBJECT_STORAGE_NETWORK_TYPE_PUBLIC.to_string(),
+            family: "IPv4".to_string(),
+            uuid: None,
+        }
+    }
+
+    pub fn private(name: impl Into<String>, network_uuid: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            network_type: OBJECT_STORAGE_NETWORK_TYPE_PRIVATE.to_string(),
+            family: "IPv4".to_string(),
+            uuid: Some(network_uuid.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateObjectStorageRequest {
+    pub name: String,
+    pub region: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configured_status: Option<ObjectStorageConfiguredStatus>,
+    pub networks: Vec<ObjectStorageNetwork>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "labels_as_list")]
+    pub labels: Option<Labels>,
+}
+
+impl CreateObjectStorageRequest {
+    pub fn new(name: impl Into<String>, region: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            region: region.into(),
+            configured_status: None,
+            networks: Vec::new(),
+            labels: None,
+        }
+    }
+
+    pub fn with_configured_status(mut self, status: ObjectStorageConfiguredStatus) -> Self {
+        self.configured_status = Some(status);
+        self
+    }
+
+    pub fn with_network(mut self, network: ObjectStorageNetwork) -> Self {
+        self.networks.push(network);
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyObjectStorageRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configured_status: Option<ObjectStorageConfiguredStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub networks: Option<Vec<ObjectStorageNetwork>>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "labels_as_list")]
+    pub labels: Option<Labels>,
+}
+
+impl ModifyObjectStorageRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_configured_status(mut self, status: ObjectStorageConfiguredStatus) -> Self {
+        self.configured_status = Some(status);
+        self
+    }
+
+    pub fn with_network(mut self, network: ObjectStorageNetwork) -> Self {
+        self.networks.get_or_insert_with(Vec::new).push(network);
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectStorageRegion {
+    pub name: String,
+    #[serde(default)]
+    pub primary_zone: String,
+    #[serde(default)]
+    pub zones: Vec<ObjectStorageRegionZone>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectStorageRegionZone {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectStorageUser {
+    pub username: String,
+    #[serde(default)]
+    pub arn: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub access_keys: Vec<ObjectStorageAccessKey>,
+    #[serde(default)]
+    pub policies: Vec<ObjectStoragePolicyRef>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateObjectStorageUserRequest {
+    pub username: String,
+}
+
+impl CreateObjectStorageUserRequest {
+    pub fn new(username: impl Into<String>) -> Self {
+        Self { username: username.into() }
+    }
+}
+
+/// An access key of an object storage user. `secret_access_key` is only
+/// returned once, when the key is created.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectStorageAccessKey {
+    pub access_key_id: String,
+    #[serde(default)]
+    pub secret_access_key: Option<String>,
+    pub status: AccessKeyStatus,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub last_used_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModifyObjectStorageAccessKeyRequest {
+    pub status: AccessKeyStatus,
+}
+
+impl ModifyObjectStorageAccessKeyRequest {
+    pub fn new(status: AccessKeyStatus) -> Self {
+        Self { status }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectStoragePolicy {
+    pub name: String,
+    #[serde(default)]
+    pub arn: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub document: String,
+    #[serde(default)]
+    pub system: bool,
+    #[serde(default)]
+    pub attachment_count: i32,
+    #[serde(default)]
+    pub default_version_id: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateObjectStoragePolicyRequest {
+    pub name: String,
+    /// URL-encoded IAM policy document.
+    pub document: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl CreateObjectStoragePolicyRequest {
+    pub fn new(name: impl Into<String>, document: &str) -> Self {
+        Self {
+            name: name.into(),
+            document: urlencoding::encode(document).into_owned(),
+            description: None,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectStoragePolicyRef {
+    pub name: String,
+    #[serde(default, skip_serializing)]
+    pub arn: String,
+}
+
+impl ObjectStoragePolicyRef {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), arn: String::new() }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObjectStorageBucket {
+    pub name: String,
+    #[serde(default)]
+    pub total_objects: i64,
+    #[serde(default)]
+    pub total_size_bytes: i64,
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateObjectStorageBucketRequest {
+    pub name: String,
+}
+
+impl CreateObjectStorageBucketRequest {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
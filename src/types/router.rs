@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use crate::types::common::Labels;
+
+pub const STATIC_ROUTE_TYPE_USER: &str = "user";
+pub const STATIC_ROUTE_TYPE_SERVICE: &str = "service";
+
+#[derive(Debug, Deserialize)]
+pub struct GetRoutersResponse {
+    pub routers: RouterList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetRouterDetailsResponse {
+    pub router: Router,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RouterList {
+    #[serde(default)]
+    pub router: Vec<Router>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Router {
+    #[serde(default)]
+    pub attached_networks: RouterNetworkList,
+    #[serde(default)]
+    pub labels: Option<Labels>,
+    pub name: String,
+    #[serde(default)]
+    pub static_routes: Vec<StaticRoute>,
+    #[serde(rename = "type", default)]
+    pub router_type: String,
+    pub uuid: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RouterNetworkList {
+    #[serde(default)]
+    pub network: Vec<RouterNetwork>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RouterNetwork {
+    pub uuid: String,
+}
+
+/// A static route of a router. `route` is the destination in CIDR notation
+/// and `nexthop` the gateway address it is forwarded to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaticRoute {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    pub route: String,
+    pub nexthop: String,
+    #[serde(rename = "type", default, skip_serializing)]
+    pub route_type: String,
+}
+
+impl StaticRoute {
+    pub fn new(route: impl Into<String>, nexthop: impl Into<String>) -> Self {
+        Self {
+            route: route.into(),
+            nexthop: nexthop.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct RouterRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Labels>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub static_routes: Option<Vec<StaticRoute>>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateRouterRequest {
+    pub router: RouterRequest,
+}
+
+impl CreateRouterRequest {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            router: RouterRequest {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.router.labels = Some(labels);
+        self
+    }
+
+    pub fn with_static_route(mut self, route: StaticRoute) -> Self {
+        self.router.static_routes.get_or_insert_with(Vec::new).push(route);
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyRouter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Labels>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub static_routes: Option<Vec<StaticRoute>>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyRouterRequest {
+    pub router: ModifyRouter,
+}
+
+impl ModifyRouterRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.router.name = Some(name.into());
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.router.labels = Some(labels);
+        self
+    }
+
+    /// Replaces the static routes of the router. Routes not added here are
+    /// removed.
+    pub fn with_static_route(mut self, route: StaticRoute) -> Self {
+        self.router.static_routes.get_or_insert_with(Vec::new).push(route);
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetworkRouter {
+    pub router: Option<String>,
+}
+
+/// Attaches a router to a network, or detaches it when `router` is `None`.
+#[derive(Debug, Serialize)]
+pub struct NetworkRouterRequest {
+    pub network: NetworkRouter,
+}
+
+impl NetworkRouterRequest {
+    pub fn attach(router: impl Into<String>) -> Self {
+        Self {
+            network: NetworkRouter { router: Some(router.into()) },
+        }
+    }
+
+    pub fn detach() -> Self {
+        Self {
+            network: NetworkRouter { router: None },
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use crate::types::common::{Labels, Label, Tags};
 
 pub const VIDEO_MODEL_VGA: &str = "vga";
@@ -19,6 +19,108 @@ pub const CREATE_SERVER_STORAGE_DEVICE_ACTION_CREATE: &str = "create";
 pub const CREATE_SERVER_STORAGE_DEVICE_ACTION_CLONE: &str = "clone";
 pub const CREATE_SERVER_STORAGE_DEVICE_ACTION_ATTACH: &str = "attach";
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BootDevice {
+    Disk,
+    Cdrom,
+    Network,
+    /// A device not known to this SDK, kept verbatim.
+    Unknown(String),
+}
+
+impl BootDevice {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Disk => "disk",
+            Self::Cdrom => "cdrom",
+            Self::Network => "network",
+            Self::Unknown(device) => device,
+        }
+    }
+}
+
+impl std::str::FromStr for BootDevice {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "disk" => Self::Disk,
+            "cdrom" => Self::Cdrom,
+            "network" => Self::Network,
+            other => Self::Unknown(other.to_string()),
+        })
+    }
+}
+
+/// Ordered list of devices a server tries to boot from, e.g. `cdrom,disk`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BootOrder {
+    pub devices: Vec<BootDevice>,
+}
+
+impl BootOrder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, device: BootDevice) -> Self {
+        self.devices.push(device);
+        self
+    }
+}
+
+impl std::fmt::Display for BootOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let devices = self.devices
+            .iter()
+            .map(BootDevice::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}", devices)
+    }
+}
+
+impl From<BootOrder> for String {
+    fn from(boot_order: BootOrder) -> Self {
+        boot_order.to_string()
+    }
+}
+
+impl std::str::FromStr for BootOrder {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let devices = s
+            .split(',')
+            .filter(|device| !device.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { devices })
+    }
+}
+
+impl Serialize for BootOrder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BootOrder {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.parse() {
+            Ok(boot_order) => Ok(boot_order),
+            Err(never) => match never {},
+        }
+    }
+}
+
 pub enum ServerState {
     Started,
     Stopped,
@@ -91,7 +193,7 @@ pub struct Server {
 pub struct ServerDetails {
     #[serde(flatten)]
     pub server: Server,
-    pub boot_order: Option<String>,
+    pub boot_order: Option<BootOrder>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub firewall: Option<String>,
     #[serde(rename = "ip_addresses")]
@@ -113,6 +215,7 @@ pub struct StorageDeviceListWrapper {
     pub storage_device: Vec<ServerStorageDevice>,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for StorageDeviceListWrapper {
     fn default() -> Self {
         Self {
@@ -121,16 +224,31 @@ impl Default for StorageDeviceListWrapper {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct IPAddress {
+    #[serde(default)]
     pub access: String,
     pub family: String,
     pub address: String,
+    #[serde(default)]
     pub ptr_record: String,
+    #[serde(default)]
     pub server: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_bool_as_yes_no",
+        deserialize_with = "deserialize_yes_no_as_option_bool"
+    )]
     pub floating: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mac: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_of_plan: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
 }
 
 #[derive(Debug, Serialize, Clone, Deserialize)]
@@ -154,26 +272,58 @@ pub struct ServerStorageDevice {
     pub boot_disk: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]    
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct NetworkInterface {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ip_addresses_as_wrapper")]
     pub ip_addresses: Option<Vec<IPAddress>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
     pub interface_type: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mac: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_bool_as_yes_no",
+        deserialize_with = "deserialize_yes_no_as_option_bool"
+    )]
     pub source_ip_filtering: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_bool_as_yes_no",
+        deserialize_with = "deserialize_yes_no_as_option_bool"
+    )]
     pub bootable: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl NetworkInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_index(mut self, index: i32) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    pub fn with_source_ip_filtering(mut self, enabled: bool) -> Self {
+        self.source_ip_filtering = Some(enabled);
+        self
+    }
+
+    pub fn with_bootable(mut self, bootable: bool) -> Self {
+        self.bootable = Some(bootable);
+        self
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ServerNetworking {
+    #[serde(with = "interfaces_as_wrapper")]
     pub interfaces: Vec<NetworkInterface>,
 }
 
@@ -297,8 +447,8 @@ impl CreateServerRequest {
         self
     }
 
-    pub fn with_boot_order(mut self, boot_order: String) -> Self {
-        self.server.boot_order = Some(boot_order);
+    pub fn with_boot_order(mut self, boot_order: impl Into<String>) -> Self {
+        self.server.boot_order = Some(boot_order.into());
         self
     }
 
@@ -327,8 +477,8 @@ impl CreateServerRequest {
         self
     }
 
-    pub fn with_server_group(mut self, server_group: String) -> Self {
-        self.server.server_group = Some(server_group);
+    pub fn with_server_group(mut self, server_group: impl Into<String>) -> Self {
+        self.server.server_group = Some(server_group.into());
         self
     }
 
@@ -337,8 +487,8 @@ impl CreateServerRequest {
         self
     }
 
-    pub fn with_timezone(mut self, timezone: String) -> Self {
-        self.server.timezone = Some(timezone);
+    pub fn with_timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.server.timezone = Some(timezone.into());
         self
     }
 
@@ -558,25 +708,85 @@ pub struct RestartServerResponse {
 }
 
 #[derive(Debug, Serialize)]
+pub struct ModifyServerRequestWrapper {
+    pub server: ModifyServerRequest,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
 pub struct ModifyServerRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub boot_order: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub core_number: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub memory_amount: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub zone: Option<String>,
     // Add other fields as needed
 }
 
+impl ModifyServerRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_boot_order(mut self, boot_order: impl Into<String>) -> Self {
+        self.boot_order = Some(boot_order.into());
+        self
+    }
+
+    pub fn with_core_number(mut self, core_number: i32) -> Self {
+        self.core_number = Some(core_number);
+        self
+    }
+
+    pub fn with_memory_amount(mut self, memory_amount: i32) -> Self {
+        self.memory_amount = Some(memory_amount);
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_zone(mut self, zone: impl Into<String>) -> Self {
+        self.zone = Some(zone.into());
+        self
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ModifyServerResponse {
     pub server: ServerDetails,
 }
 
+#[derive(Debug, Serialize)]
+pub struct LoadCdromDevice {
+    pub storage: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoadCdromRequest {
+    pub storage_device: LoadCdromDevice,
+}
+
+impl LoadCdromRequest {
+    pub fn new(storage: impl Into<String>) -> Self {
+        Self {
+            storage_device: LoadCdromDevice { storage: storage.into() },
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct StorageDeviceWrapper {
     pub storage_device: Vec<CreateServerStorageDevice>,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for StorageDeviceWrapper {
     fn default() -> Self {
         Self {
@@ -585,7 +795,7 @@ impl Default for StorageDeviceWrapper {
     }
 }
 
-fn deserialize_yes_no_as_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+pub(crate) fn deserialize_yes_no_as_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
@@ -593,7 +803,15 @@ where
     Ok(s == "yes")
 }
 
-fn serialize_bool_as_yes_no<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+pub(crate) fn deserialize_yes_no_as_option_bool<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s = Option::<String>::deserialize(deserializer)?;
+    Ok(s.map(|s| s == "yes"))
+}
+
+pub(crate) fn serialize_bool_as_yes_no<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
 {
@@ -604,7 +822,71 @@ where
     }
 }
 
+pub(crate) fn serialize_required_bool_as_yes_no<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(if *value { "yes" } else { "no" })
+}
+
+/// The API nests interface IP addresses as `{"ip_address": [...]}`; this
+/// keeps [`NetworkInterface::ip_addresses`] a plain list.
+mod ip_addresses_as_wrapper {
+    use super::IPAddress;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    struct WrapperRef<'a> {
+        ip_address: &'a [IPAddress],
+    }
+
+    #[derive(Deserialize)]
+    struct Wrapper {
+        #[serde(default)]
+        ip_address: Vec<IPAddress>,
+    }
+
+    pub fn serialize<S: Serializer>(ip_addresses: &Option<Vec<IPAddress>>, serializer: S) -> Result<S::Ok, S::Error> {
+        match ip_addresses {
+            Some(ip_address) => WrapperRef { ip_address }.serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<IPAddress>>, D::Error> {
+        let wrapper = Option::<Wrapper>::deserialize(deserializer)?;
+        Ok(wrapper.map(|wrapper| wrapper.ip_address))
+    }
+}
+
+/// The API nests server interfaces as `{"interface": [...]}`; this keeps
+/// [`ServerNetworking::interfaces`] a plain list.
+mod interfaces_as_wrapper {
+    use super::NetworkInterface;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    struct WrapperRef<'a> {
+        interface: &'a [NetworkInterface],
+    }
+
+    #[derive(Deserialize)]
+    struct Wrapper {
+        #[serde(default)]
+        interface: Vec<NetworkInterface>,
+    }
+
+    pub fn serialize<S: Serializer>(interfaces: &[NetworkInterface], serializer: S) -> Result<S::Ok, S::Error> {
+        WrapperRef { interface: interfaces }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<NetworkInterface>, D::Error> {
+        Ok(Wrapper::deserialize(deserializer)?.interface)
+    }
+}
+
 // Add these builder implementations
+#[allow(clippy::new_without_default)]
 impl CreateServerNetworking {
     pub fn new() -> Self {
         Self {
@@ -638,6 +920,11 @@ impl CreateServerInterface {
         self
     }
 
+    pub fn with_network(mut self, network: impl Into<String>) -> Self {
+        self.network = Some(network.into());
+        self
+    }
+
     pub fn with_source_ip_filtering(mut self, enabled: bool) -> Self {
         self.source_ip_filtering = Some(if enabled { "yes" } else { "no" }.to_string());
         self
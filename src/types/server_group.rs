@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use crate::types::common::Labels;
+
+/// Anti-affinity policy of a server group.
+///
+/// With `Strict`, servers that cannot be placed on separate hosts fail to
+/// start. With `Yes`, placement on separate hosts is best effort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AntiAffinityPolicy {
+    Strict,
+    Yes,
+    #[default]
+    No,
+}
+
+impl AntiAffinityPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AntiAffinityPolicy::Strict => "strict",
+            AntiAffinityPolicy::Yes => "yes",
+            AntiAffinityPolicy::No => "no",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AntiAffinityState {
+    Met,
+    Unmet,
+    /// A state not known to this SDK.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetServerGroupsResponse {
+    pub server_groups: ServerGroupList,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerGroupList {
+    pub server_group: Vec<ServerGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetServerGroupResponse {
+    pub server_group: ServerGroup,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ServerGroupServers {
+    pub server: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerGroup {
+    pub uuid: String,
+    pub title: String,
+    #[serde(default)]
+    pub anti_affinity: AntiAffinityPolicy,
+    #[serde(default)]
+    pub anti_affinity_status: Vec<AntiAffinityStatus>,
+    #[serde(default)]
+    pub labels: Option<Labels>,
+    #[serde(default)]
+    pub servers: ServerGroupServers,
+}
+
+impl ServerGroup {
+    /// Returns true if every member server is placed according to the
+    /// anti-affinity policy of the group.
+    pub fn anti_affinity_met(&self) -> bool {
+        self.anti_affinity_status.iter().all(|s| s.status == AntiAffinityState::Met)
+    }
+
+    /// Returns the member servers that share a host with another member.
+    pub fn unmet_servers(&self) -> Vec<&str> {
+        self.anti_affinity_status
+            .iter()
+            .filter(|s| s.status == AntiAffinityState::Unmet)
+            .map(|s| s.uuid.as_str())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AntiAffinityStatus {
+    pub uuid: String,
+    pub status: AntiAffinityState,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ServerGroupRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anti_affinity: Option<AntiAffinityPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Labels>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub servers: Option<ServerGroupServers>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateServerGroupRequest {
+    pub server_group: ServerGroupRequest,
+}
+
+impl CreateServerGroupRequest {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            server_group: ServerGroupRequest {
+                title: Some(title.into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_anti_affinity(mut self, policy: AntiAffinityPolicy) -> Self {
+        self.server_group.anti_affinity = Some(policy);
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.server_group.labels = Some(labels);
+        self
+    }
+
+    pub fn with_server(mut self, uuid: impl Into<String>) -> Self {
+        self.server_group.servers.get_or_insert_with(ServerGroupServers::default).server.push(uuid.into());
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyServerGroupRequest {
+    pub server_group: ServerGroupRequest,
+}
+
+impl ModifyServerGroupRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.server_group.title = Some(title.into());
+        self
+    }
+
+    pub fn with_anti_affinity(mut self, policy: AntiAffinityPolicy) -> Self {
+        self.server_group.anti_affinity = Some(policy);
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.server_group.labels = Some(labels);
+        self
+    }
+
+    /// Replaces the member servers of the group.
+    pub fn with_servers(mut self, servers: Vec<String>) -> Self {
+        self.server_group.servers = Some(ServerGroupServers { server: servers });
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServerGroupMember {
+    pub uuid: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AddServerGroupMemberRequest {
+    pub server: ServerGroupMember,
+}
+
+impl AddServerGroupMemberRequest {
+    pub fn new(uuid: impl Into<String>) -> Self {
+        Self { server: ServerGroupMember { uuid: uuid.into() } }
+    }
+}
@@ -0,0 +1,518 @@
+use serde::{Deserialize, Serialize};
+use crate::types::common::{Labels, deserialize_empty_as_none};
+use crate::types::server::{deserialize_yes_no_as_bool, serialize_bool_as_yes_no, serialize_required_bool_as_yes_no};
+
+pub const STORAGE_TIER_HDD: &str = "hdd";
+pub const STORAGE_TIER_STANDARD: &str = "standard";
+pub const STORAGE_TIER_MAXIOPS: &str = "maxiops";
+
+pub const STORAGE_DEVICE_TYPE_DISK: &str = "disk";
+pub const STORAGE_DEVICE_TYPE_CDROM: &str = "cdrom";
+
+pub const STORAGE_IMPORT_SOURCE_HTTP_IMPORT: &str = "http_import";
+pub const STORAGE_IMPORT_SOURCE_DIRECT_UPLOAD: &str = "direct_upload";
+
+pub const TEMPLATE_TYPE_NATIVE: &str = "native";
+pub const TEMPLATE_TYPE_CLOUD_INIT: &str = "cloud-init";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageType {
+    Normal,
+    Backup,
+    Cdrom,
+    Template,
+}
+
+impl StorageType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Backup => "backup",
+            Self::Cdrom => "cdrom",
+            Self::Template => "template",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageAccess {
+    Public,
+    Private,
+}
+
+impl StorageAccess {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Private => "private",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageState {
+    Online,
+    Maintenance,
+    Cloning,
+    Backuping,
+    Syncing,
+    Error,
+}
+
+impl StorageState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Online => "online",
+            Self::Maintenance => "maintenance",
+            Self::Cloning => "cloning",
+            Self::Backuping => "backuping",
+            Self::Syncing => "syncing",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupRuleInterval {
+    Daily,
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+/// Automatic backup schedule of a storage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupRule {
+    pub interval: BackupRuleInterval,
+    /// Time of day in `hhmm` format, e.g. `0430`.
+    pub time: String,
+    /// Number of days the backups are kept.
+    pub retention: i32,
+}
+
+impl BackupRule {
+    pub fn new(interval: BackupRuleInterval, time: impl Into<String>, retention: i32) -> Self {
+        Self {
+            interval,
+            time: time.into(),
+            retention,
+        }
+    }
+}
+
+/// Reference to another storage by UUID, e.g. the source disk of a backup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StorageRef(pub String);
+
+impl StorageRef {
+    pub fn uuid(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetStorageResponse {
+    pub storages: StorageList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetStorageDetailsResponse {
+    pub storage: StorageDetails,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StorageList {
+    #[serde(default)]
+    pub storage: Vec<Storage>,
+}
+
+#[derive(Debug, Default, Serialize, Clone, Deserialize)]
+pub struct Storage {
+    pub access: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(default, serialize_with = "serialize_required_bool_as_yes_no", deserialize_with = "deserialize_yes_no_as_bool")]
+    pub encrypted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Labels>,
+    #[serde(default)]
+    pub license: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_empty_as_none")]
+    pub origin: Option<StorageRef>,
+    #[serde(default)]
+    pub part_of_plan: String,
+    pub size: i32,
+    pub state: String,
+    #[serde(default)]
+    pub tier: String,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub storage_type: String,
+    /// Set for templates: `native` or `cloud-init`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_type: Option<String>,
+    pub uuid: String,
+    #[serde(default)]
+    pub zone: String,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct StorageDetails {
+    #[serde(flatten)]
+    pub storage: Storage,
+    #[serde(default, deserialize_with = "deserialize_empty_as_none")]
+    pub backup_rule: Option<BackupRule>,
+    #[serde(default)]
+    pub backups: StorageBackupUUIDs,
+    #[serde(default)]
+    pub servers: StorageServerUUIDs,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct StorageBackupUUIDs {
+    #[serde(default)]
+    pub backup: Vec<StorageRef>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct StorageServerUUIDs {
+    #[serde(default)]
+    pub server: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct StorageRequest {
+    pub size: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<String>,
+    pub title: String,
+    pub zone: String,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_bool_as_yes_no")]
+    pub encrypted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Labels>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_rule: Option<BackupRule>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateStorageRequest {
+    pub storage: StorageRequest,
+}
+
+impl CreateStorageRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_size(mut self, size: i32) -> Self {
+        self.storage.size = size;
+        self
+    }
+
+    pub fn with_tier(mut self, tier: impl Into<String>) -> Self {
+        self.storage.tier = Some(tier.into());
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.storage.title = title.into();
+        self
+    }
+
+    pub fn with_zone(mut self, zone: impl Into<String>) -> Self {
+        self.storage.zone = zone.into();
+        self
+    }
+
+    pub fn with_encrypted(mut self, encrypted: bool) -> Self {
+        self.storage.encrypted = Some(encrypted);
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.storage.labels = Some(labels);
+        self
+    }
+
+    pub fn with_backup_rule(mut self, backup_rule: BackupRule) -> Self {
+        self.storage.backup_rule = Some(backup_rule);
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyStorage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Labels>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_rule: Option<BackupRule>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyStorageRequest {
+    pub storage: ModifyStorage,
+}
+
+impl ModifyStorageRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_size(mut self, size: i32) -> Self {
+        self.storage.size = Some(size);
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.storage.title = Some(title.into());
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.storage.labels = Some(labels);
+        self
+    }
+
+    pub fn with_backup_rule(mut self, backup_rule: BackupRule) -> Self {
+        self.storage.backup_rule = Some(backup_rule);
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AttachStorageDevice {
+    #[serde(rename = "type")]
+    pub device_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    pub storage: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boot_disk: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AttachStorageRequest {
+    pub storage_device: AttachStorageDevice,
+}
+
+impl AttachStorageRequest {
+    pub fn new(storage: impl Into<String>) -> Self {
+        Self {
+            storage_device: AttachStorageDevice {
+                device_type: STORAGE_DEVICE_TYPE_DISK.to_string(),
+                address: None,
+                storage: storage.into(),
+                boot_disk: None,
+            },
+        }
+    }
+
+    pub fn with_type(mut self, device_type: impl Into<String>) -> Self {
+        self.storage_device.device_type = device_type.into();
+        self
+    }
+
+    pub fn with_address(mut self, address: impl Into<String>) -> Self {
+        self.storage_device.address = Some(address.into());
+        self
+    }
+
+    pub fn with_boot_disk(mut self, boot_disk: bool) -> Self {
+        self.storage_device.boot_disk = Some(if boot_disk { "1" } else { "0" }.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DetachStorageDevice {
+    pub address: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DetachStorageRequest {
+    pub storage_device: DetachStorageDevice,
+}
+
+impl DetachStorageRequest {
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            storage_device: DetachStorageDevice {
+                address: address.into(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CloneStorage {
+    pub zone: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_bool_as_yes_no")]
+    pub encrypted: Option<bool>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CloneStorageRequest {
+    pub storage: CloneStorage,
+}
+
+impl CloneStorageRequest {
+    pub fn new(zone: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            storage: CloneStorage {
+                zone: zone.into(),
+                title: title.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_tier(mut self, tier: impl Into<String>) -> Self {
+        self.storage.tier = Some(tier.into());
+        self
+    }
+
+    pub fn with_encrypted(mut self, encrypted: bool) -> Self {
+        self.storage.encrypted = Some(encrypted);
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplatizeStorage {
+    pub title: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplatizeStorageRequest {
+    pub storage: TemplatizeStorage,
+}
+
+impl TemplatizeStorageRequest {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            storage: TemplatizeStorage { title: title.into() },
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct StorageImport {
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateStorageImportRequest {
+    pub storage_import: StorageImport,
+}
+
+impl CreateStorageImportRequest {
+    /// Import the storage contents from a publicly reachable HTTP(S) URL.
+    pub fn http_import(source_location: impl Into<String>) -> Self {
+        Self {
+            storage_import: StorageImport {
+                source: STORAGE_IMPORT_SOURCE_HTTP_IMPORT.to_string(),
+                source_location: Some(source_location.into()),
+                content_hash: None,
+            },
+        }
+    }
+
+    /// Prepare a direct upload. The returned import contains the
+    /// `direct_upload_url` the image should be uploaded to.
+    pub fn direct_upload() -> Self {
+        Self {
+            storage_import: StorageImport {
+                source: STORAGE_IMPORT_SOURCE_DIRECT_UPLOAD.to_string(),
+                source_location: None,
+                content_hash: None,
+            },
+        }
+    }
+
+    pub fn with_content_hash(mut self, content_hash: impl Into<String>) -> Self {
+        self.storage_import.content_hash = Some(content_hash.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StorageImportResponse {
+    pub storage_import: StorageImportDetails,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct StorageImportDetails {
+    #[serde(default)]
+    pub client_content_length: i64,
+    #[serde(default)]
+    pub client_content_type: String,
+    #[serde(default)]
+    pub completed: String,
+    #[serde(default)]
+    pub created: String,
+    #[serde(default)]
+    pub direct_upload_url: String,
+    #[serde(default)]
+    pub error_code: String,
+    #[serde(default)]
+    pub error_message: String,
+    #[serde(default)]
+    pub md5sum: String,
+    #[serde(default)]
+    pub read_bytes: i64,
+    #[serde(default)]
+    pub sha256sum: String,
+    pub source: String,
+    #[serde(default)]
+    pub source_location: String,
+    pub state: String,
+    pub uuid: String,
+    #[serde(default)]
+    pub written_bytes: i64,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct StorageImportUploadResult {
+    #[serde(default)]
+    pub written_bytes: i64,
+    #[serde(default)]
+    pub md5sum: String,
+    #[serde(default)]
+    pub sha256sum: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateBackup {
+    pub title: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateBackupRequest {
+    pub storage: CreateBackup,
+}
+
+impl CreateBackupRequest {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            storage: CreateBackup { title: title.into() },
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct GetTagsResponse {
+    pub tags: TagList,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TagList {
+    #[serde(default)]
+    pub tag: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetTagResponse {
+    pub tag: Tag,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagServers {
+    pub server: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Tag {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub servers: TagServers,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TagRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub servers: Option<TagServers>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateTagRequest {
+    pub tag: TagRequest,
+}
+
+impl CreateTagRequest {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            tag: TagRequest {
+                name: Some(name.into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.tag.description = Some(description.into());
+        self
+    }
+
+    pub fn with_server(mut self, uuid: impl Into<String>) -> Self {
+        self.tag.servers.get_or_insert_with(TagServers::default).server.push(uuid.into());
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyTagRequest {
+    pub tag: TagRequest,
+}
+
+impl ModifyTagRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renames the tag. Servers carrying the tag keep it under the new name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.tag.name = Some(name.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.tag.description = Some(description.into());
+        self
+    }
+
+    /// Replaces the servers the tag is assigned to.
+    pub fn with_servers(mut self, servers: Vec<String>) -> Self {
+        self.tag.servers = Some(TagServers { server: servers });
+        self
+    }
+}
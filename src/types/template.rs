@@ -0,0 +1,87 @@
+use crate::types::storage::{Storage, StorageAccess};
+
+/// Criteria for looking up templates from the catalog. All set criteria
+/// must match; text comparisons ignore case.
+#[derive(Debug, Default)]
+pub struct TemplateFilter {
+    title: Option<String>,
+    os_family: Option<String>,
+    version: Option<String>,
+    access: Option<StorageAccess>,
+    template_type: Option<String>,
+}
+
+impl TemplateFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches templates whose title starts with the given text, e.g.
+    /// `Ubuntu Server 24.04` matches `Ubuntu Server 24.04 LTS (Noble Numbat)`.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Matches templates whose title mentions the OS family, e.g. `debian`
+    /// or `windows`.
+    pub fn with_os_family(mut self, os_family: impl Into<String>) -> Self {
+        self.os_family = Some(os_family.into());
+        self
+    }
+
+    /// Matches templates whose title contains the version as a separate
+    /// word, e.g. `24.04` or `2022`.
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn with_access(mut self, access: StorageAccess) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    pub fn with_template_type(mut self, template_type: impl Into<String>) -> Self {
+        self.template_type = Some(template_type.into());
+        self
+    }
+
+    pub fn matches(&self, template: &Storage) -> bool {
+        let title = template.title.to_lowercase();
+
+        if let Some(prefix) = &self.title {
+            if !title.starts_with(&prefix.to_lowercase()) {
+                return false;
+            }
+        }
+
+        if let Some(os_family) = &self.os_family {
+            if !title.contains(&os_family.to_lowercase()) {
+                return false;
+            }
+        }
+
+        if let Some(version) = &self.version {
+            let version = version.to_lowercase();
+            let mut words = title.split(|c: char| c.is_whitespace() || c == '(' || c == ')');
+            if !words.any(|word| word == version) {
+                return false;
+            }
+        }
+
+        if let Some(access) = &self.access {
+            if template.access != access.as_str() {
+                return false;
+            }
+        }
+
+        if let Some(template_type) = &self.template_type {
+            if template.template_type.as_deref() != Some(template_type.as_str()) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// An API token. The secret `token` value is only returned when the token
+/// is created.
+#[derive(Clone, Deserialize)]
+pub struct Token {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub token: Option<String>,
+    #[serde(rename = "type", default)]
+    pub token_type: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub expires_at: String,
+    #[serde(default)]
+    pub last_used_at: Option<String>,
+    #[serde(default)]
+    pub can_create_tokens: bool,
+    #[serde(default)]
+    pub allowed_ip_ranges: Vec<String>,
+    #[serde(default)]
+    pub gui: bool,
+}
+
+impl std::fmt::Debug for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Token")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("token", &self.token.as_ref().map(|_| "<redacted>"))
+            .field("token_type", &self.token_type)
+            .field("created_at", &self.created_at)
+            .field("expires_at", &self.expires_at)
+            .field("last_used_at", &self.last_used_at)
+            .field("can_create_tokens", &self.can_create_tokens)
+            .field("allowed_ip_ranges", &self.allowed_ip_ranges)
+            .field("gui", &self.gui)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateTokenRequest {
+    pub name: String,
+    /// Expiry time in RFC 3339 format, e.g. `2026-12-31T00:00:00Z`.
+    pub expires_at: String,
+    pub can_create_tokens: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_ip_ranges: Vec<String>,
+}
+
+impl CreateTokenRequest {
+    pub fn new(name: impl Into<String>, expires_at: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            expires_at: expires_at.into(),
+            can_create_tokens: false,
+            allowed_ip_ranges: Vec::new(),
+        }
+    }
+
+    pub fn with_can_create_tokens(mut self, can_create_tokens: bool) -> Self {
+        self.can_create_tokens = can_create_tokens;
+        self
+    }
+
+    /// Restricts use of the token to the given address or CIDR block.
+    pub fn with_allowed_ip_range(mut self, range: impl Into<String>) -> Self {
+        self.allowed_ip_ranges.push(range.into());
+        self
+    }
+}